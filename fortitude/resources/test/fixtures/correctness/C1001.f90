@@ -0,0 +1,13 @@
+module value_intent
+  implicit none
+contains
+  subroutine foo(x, y, z, w)
+    integer, value, intent(inout) :: x
+    integer, VALUE, Intent(Out) :: y
+    integer, value, intent(in) :: z
+    integer, intent(inout) :: w
+    x = x + 1
+    y = 2
+    w = z
+  end subroutine foo
+end module value_intent
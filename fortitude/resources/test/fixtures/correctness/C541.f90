@@ -0,0 +1,18 @@
+program test
+  implicit none
+  integer :: i, j
+
+  do i = 1, 10
+    i = i + 1
+  end do
+
+  do i = 1, 10
+    j = i
+  end do
+
+  outer: do i = 1, 10
+    do j = 1, 10
+      if (j > 5) i = 10
+    end do
+  end do outer
+end program test
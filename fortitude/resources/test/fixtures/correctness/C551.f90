@@ -0,0 +1,11 @@
+program test
+  implicit none
+  integer :: i, a, b
+  real :: x
+
+  x = 1/3
+  i = 6/3
+  x = a/b
+  x = 1.0/3
+  x = real(a)/b
+end program test
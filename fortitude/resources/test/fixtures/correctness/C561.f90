@@ -0,0 +1,16 @@
+program test
+  implicit none
+  integer :: i, x
+
+  do 100 i = 1, 10
+    x = x + i
+  end do
+
+  do i = 1, 10
+    x = x + i
+  end do
+
+  do 200 i = 1, 10
+    x = x + i
+200 end do
+end program test
@@ -0,0 +1,8 @@
+program test
+  implicit none
+  real :: x
+  x = 1.
+  x = .5
+  x = 1.0
+  x = 2.e3
+end program test
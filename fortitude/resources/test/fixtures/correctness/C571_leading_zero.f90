@@ -0,0 +1,7 @@
+program test
+  implicit none
+  real :: x
+  x = .5
+  x = 0.5
+  x = 3.
+end program test
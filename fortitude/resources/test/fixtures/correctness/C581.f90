@@ -0,0 +1,14 @@
+program test
+  implicit none
+  integer :: i
+  real :: x
+  real :: arr(10)
+  real, dimension(5) :: arr2
+  x = arr(2.0)
+  x = 2.0 * arr(2)
+  x = arr2(3.5)
+  x = sin(2.0)
+  do i = 1.0, 10
+    x = x + arr(i)
+  end do
+end program test
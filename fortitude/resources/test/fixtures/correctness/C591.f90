@@ -0,0 +1,12 @@
+module iso_c_binding
+  implicit none
+  integer, parameter :: c_int = 4
+end module iso_c_binding
+
+module user
+  use iso_c_binding
+  use, non_intrinsic :: iso_c_binding
+  use, intrinsic :: iso_c_binding
+  use iso_fortran_env
+  implicit none
+end module user
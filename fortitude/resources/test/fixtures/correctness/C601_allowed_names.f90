@@ -0,0 +1,17 @@
+subroutine plain()
+  implicit none (type, external)
+  real, volatile :: x
+  real, volatile :: y  ! volatile: updated by signal handler
+  real, volatile :: flag_ready
+  x = 1.0
+  y = 2.0
+  flag_ready = .true.
+end subroutine plain
+
+subroutine threaded()
+  implicit none (type, external)
+  real, volatile :: z
+  !$omp parallel
+  z = 1.0
+  !$omp end parallel
+end subroutine threaded
@@ -0,0 +1,25 @@
+module config
+  implicit none (type, external)
+  real, protected :: x = 0.0
+  real :: y = 0.0
+contains
+  subroutine set_x(value)
+    real, intent(in) :: value
+    x = value
+  end subroutine set_x
+end module config
+
+subroutine modify()
+  use config
+  implicit none (type, external)
+  x = 1.0
+  y = 1.0
+end subroutine modify
+
+subroutine shadow()
+  use config, only: y
+  implicit none (type, external)
+  real :: x
+  x = 1.0
+  y = 2.0
+end subroutine shadow
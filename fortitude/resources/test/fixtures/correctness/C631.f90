@@ -0,0 +1,23 @@
+subroutine host(n)
+  implicit none (type, external)
+  integer, intent(in) :: n
+  real :: x, y
+  x = 0.0
+  y = 0.0
+  call inner(y)
+contains
+  subroutine inner(y)
+    real, intent(inout) :: y
+    real :: x
+    integer :: n
+    x = 1.0
+    n = 2
+    y = x
+  end subroutine inner
+
+  real function fine()
+    real :: z
+    z = x
+    fine = z
+  end function fine
+end subroutine host
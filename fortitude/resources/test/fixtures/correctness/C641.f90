@@ -0,0 +1,21 @@
+module functions
+  implicit none (type, external)
+  interface
+    integer function external_answer()
+    end function external_answer
+  end interface
+contains
+  integer function answer()
+    integer :: unused
+  end function answer
+
+  integer function early()
+    early = 1
+    return
+  end function early
+
+  real function assigned(x)
+    real, intent(in) :: x
+    assigned = 2.0 * x
+  end function assigned
+end module functions
@@ -0,0 +1,28 @@
+module c_pointers
+  use, intrinsic :: iso_c_binding, only: c_ptr, c_associated, c_f_pointer
+  implicit none (type, external)
+contains
+  subroutine unchecked(ptr, n)
+    type(c_ptr), intent(in) :: ptr
+    integer, intent(in) :: n
+    real, pointer :: array(:)
+    call c_f_pointer(ptr, array, [n])
+  end subroutine unchecked
+
+  subroutine checked(ptr, n)
+    type(c_ptr), intent(in) :: ptr
+    integer, intent(in) :: n
+    real, pointer :: array(:)
+    if (c_associated(ptr)) then
+      call c_f_pointer(ptr, array, [n])
+    end if
+  end subroutine checked
+
+  subroutine early_return(ptr, n)
+    type(c_ptr), intent(in) :: ptr
+    integer, intent(in) :: n
+    real, pointer :: array(:)
+    if (.not. c_associated(ptr)) return
+    call c_f_pointer(ptr, array, [n])
+  end subroutine early_return
+end module c_pointers
@@ -0,0 +1,21 @@
+subroutine check(x, y)
+  implicit none (type, external)
+  real, intent(in) :: x
+  real, intent(out) :: y
+  y = 0.0
+  positive: if (x > 0) then
+    y = sqrt(x)
+  end if
+  negative: if (x < 0) then
+    y = -x
+  end if negative
+  if (x == 0) then
+    y = 1.0
+  end if
+  other: if (x > 100) then
+    y = 100.0
+  end if wrong
+  UPPER: IF (x > 10) THEN
+    y = 10.0
+  ENDIF
+end subroutine check
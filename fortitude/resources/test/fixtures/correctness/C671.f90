@@ -0,0 +1,17 @@
+module physics
+  implicit none (type, external)
+  real :: energy = 0.0
+  real :: mass = 1.0
+end module physics
+
+subroutine report(velocity)
+  use physics, only: energy
+  implicit none (type, external)
+  real, intent(in) :: velocity
+  associate (energy => 0.5 * velocity**2)
+    print *, energy
+  end associate
+  associate (kinetic => 0.5 * velocity**2)
+    print *, kinetic
+  end associate
+end subroutine report
@@ -0,0 +1,8 @@
+subroutine copy_shape()
+  implicit none (type, external)
+  real, allocatable :: x(:), z(:)
+  integer :: y(10)
+  real :: w(10)
+  allocate (x, mold=y)
+  allocate (z, mold=w)
+end subroutine copy_shape
@@ -0,0 +1,16 @@
+subroutine roots(n, x, y)
+  implicit none (type, external)
+  integer, intent(in) :: n
+  real, intent(in) :: x(n)
+  real, intent(out) :: y(n)
+  integer :: i
+  if (any(x < 0)) stop
+  do concurrent (i = 1:n)
+    if (x(i) < 0) stop
+    if (x(i) > 100) error stop
+    y(i) = sqrt(x(i))
+  end do
+  do i = 1, n
+    if (y(i) > 10) return
+  end do
+end subroutine roots
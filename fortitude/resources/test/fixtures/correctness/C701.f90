@@ -0,0 +1,11 @@
+subroutine process(y)
+  implicit none (type, external)
+  real, intent(in) :: y
+  integer :: unit
+  open (10, file="input.dat", action="read")
+  write (10, *) y
+  close (10)
+  open (newunit=unit, file="output.dat", action='READWRITE')
+  write (unit, *) y
+  close (unit)
+end subroutine process
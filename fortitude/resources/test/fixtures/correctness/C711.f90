@@ -0,0 +1,10 @@
+subroutine names()
+  implicit none (type, external)
+  integer, parameter :: n = 0
+  integer, parameter :: m = 8
+  character(len=0) :: empty
+  character(0) :: also_empty
+  character(n) :: from_parameter
+  character(len=1) :: letter
+  character(m) :: word
+end subroutine names
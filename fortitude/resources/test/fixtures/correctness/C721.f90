@@ -0,0 +1,18 @@
+subroutine limits()
+  use, intrinsic :: iso_fortran_env, only: real32, real64
+  implicit none (type, external)
+  real :: x
+  double precision :: y
+  x = 1.0E400
+  x = 1.0E-400
+  x = 3.0e38
+  x = 1.0e39
+  y = 1.0D200
+  y = 1.0d309
+  y = 1.0e200_real64
+  x = 0.0e999
+  x = 1.0e39_real32
+  y = 1.0e400_real64
+  y = 1.0e400_8
+  y = 1.0e400_dp
+end subroutine limits
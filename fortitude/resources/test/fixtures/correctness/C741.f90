@@ -0,0 +1,14 @@
+program test
+  implicit none
+  integer :: arr(12), total(3), n
+  integer, allocatable :: matrix(:, :)
+  real :: d
+  matrix = reshape(arr, [3.0, 4])
+  matrix = reshape(arr, [3, 4])
+  matrix = reshape(arr, shape=[real :: 3, 4])
+  total = sum(matrix, dim=2.0)
+  total = sum(matrix, 2)
+  d = 1.0
+  n = size(matrix, d)
+  n = size(matrix, dim=1)
+end program test
@@ -0,0 +1,7 @@
+program test
+  implicit none
+  real :: x[*]
+  integer, codimension[*] :: counter
+  real :: y(10)[*], z
+  integer :: a(2) = [1, 2]
+end program test
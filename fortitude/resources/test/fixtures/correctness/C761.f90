@@ -0,0 +1,6 @@
+program test
+  implicit none
+  real :: x(10)
+  call random_number(x)
+  call some_other_routine(x)
+end program test
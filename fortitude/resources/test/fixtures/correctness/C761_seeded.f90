@@ -0,0 +1,11 @@
+program test
+  implicit none
+  integer :: n
+  integer, allocatable :: seed(:)
+  real :: x(10)
+  call random_seed(size=n)
+  allocate(seed(n))
+  seed = 42
+  call random_seed(put=seed)
+  call random_number(x)
+end program test
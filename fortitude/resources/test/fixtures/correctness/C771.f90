@@ -0,0 +1,14 @@
+subroutine process()
+  implicit none (type, external)
+  integer :: unit
+  open (10, file="data.bin", access="direct", recl=8)
+  backspace (10)
+  close (10)
+  open (newunit=unit, file="stream.bin", access='STREAM')
+  rewind (unit)
+  close (unit)
+  open (20, file="log.txt", access="sequential")
+  backspace (20)
+  rewind (20)
+  close (20)
+end subroutine process
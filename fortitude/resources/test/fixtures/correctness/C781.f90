@@ -0,0 +1,10 @@
+subroutine resize()
+  implicit none (type, external)
+  real, allocatable :: work(:), result(:)
+  real :: fixed(10)
+  allocate(work(10))
+  call move_alloc(work, fixed)
+  call move_alloc(from=fixed, to=result)
+  call move_alloc(work, result)
+  call move_alloc(to=work, from=result)
+end subroutine resize
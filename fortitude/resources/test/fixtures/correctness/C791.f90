@@ -0,0 +1,17 @@
+subroutine area(radius, arr, i)
+  use, intrinsic :: iso_fortran_env, only: real64
+  implicit none (type, external)
+  real(real64), intent(in) :: radius
+  type(item_t), intent(in) :: arr(:)
+  integer, intent(in) :: i
+  integer, parameter :: n = 4
+  associate (pi => 3.14159_real64)
+    print *, pi * radius**2
+  end associate
+  associate (count => n, scale => 2.0)
+    print *, count * scale
+  end associate
+  associate (a => arr(i)%component)
+    print *, a
+  end associate
+end subroutine area
@@ -0,0 +1,9 @@
+subroutine solve(n)
+  implicit none (type, external)
+  integer, intent(in) :: n
+  if (n < 0) error stop
+  if (n > 100) error stop "solve: too many iterations"
+  if (n == 42) error stop 3
+  if (n == 0) stop
+  error stop
+end subroutine solve
@@ -0,0 +1,11 @@
+subroutine cleanup(input)
+  implicit none (type, external)
+  real, intent(in) :: input(:)
+  real, allocatable :: work(:)
+  real, pointer :: view(:)
+  real :: fixed(10)
+  if (allocated(work)) deallocate(work)
+  if (allocated(view)) deallocate(view)
+  if (allocated(array=fixed)) print *, "fixed"
+  if (allocated(input)) print *, "input"
+end subroutine cleanup
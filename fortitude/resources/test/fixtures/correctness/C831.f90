@@ -0,0 +1,11 @@
+subroutine cleanup(target_array)
+  implicit none (type, external)
+  real, intent(in), target :: target_array(:)
+  real, allocatable :: work(:)
+  real, pointer :: view(:)
+  real :: fixed(10)
+  if (associated(view)) nullify(view)
+  if (associated(view, target_array)) nullify(view)
+  if (associated(work)) deallocate(work)
+  if (associated(pointer=fixed)) print *, "fixed"
+end subroutine cleanup
@@ -0,0 +1,34 @@
+module operations
+  implicit none (type, external)
+contains
+  subroutine outer(x, y)
+    real, intent(in) :: x
+    real, intent(inout) :: y
+    call scale(x)
+    call scale(y)
+    call reset(value=x)
+    call report(x)
+    call copy(x)
+  end subroutine outer
+
+  subroutine scale(a)
+    real :: a
+    a = 2 * a
+  end subroutine scale
+
+  subroutine reset(value)
+    real, intent(out) :: value
+    value = 0.0
+  end subroutine reset
+
+  subroutine report(value)
+    real, intent(in) :: value
+    print *, value
+  end subroutine report
+
+  subroutine copy(b)
+    real, value :: b
+    b = 2 * b
+    print *, b
+  end subroutine copy
+end module operations
@@ -0,0 +1,19 @@
+module solver
+  implicit none (type, external)
+contains
+  subroutine solve(x, log_unit)
+    real, intent(inout) :: x
+    integer, intent(in) :: log_unit
+    print *, "solving..."
+    write (*, *) "x = ", x
+    write (log_unit, *) "done"
+  end subroutine solve
+end module solver
+
+program main
+  use solver, only: solve
+  implicit none (type, external)
+  real :: x = 1.0
+  call solve(x, 6)
+  print *, x
+end program main
@@ -0,0 +1,7 @@
+subroutine names()
+  implicit none (type, external)
+  character(len=-1) :: negative
+  character(-4) :: also_negative
+  character(1) :: letter
+  character(len=8) :: word
+end subroutine names
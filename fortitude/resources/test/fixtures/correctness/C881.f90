@@ -0,0 +1,17 @@
+module pointers
+  implicit none
+contains
+  subroutine make_array(array, view, size)
+    real, pointer, intent(out) :: array(:)
+    real, intent(in), pointer :: view(:)
+    integer, intent(in) :: size
+    allocate(array(size))
+    array = view(1:size)
+  end subroutine make_array
+
+  subroutine point_at(target_array, ptr)
+    real, target, intent(in) :: target_array(:)
+    real, pointer :: ptr(:)
+    ptr => target_array
+  end subroutine point_at
+end module pointers
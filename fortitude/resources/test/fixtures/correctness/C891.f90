@@ -0,0 +1,24 @@
+subroutine cleanup(n)
+  implicit none (type, external)
+  integer, intent(in) :: n
+  real, allocatable :: x(:), y(:), z(:)
+  real, pointer :: p(:)
+  integer :: status
+
+  allocate(x(n), y(n), z(n), p(n))
+
+  deallocate(x)
+  print *, "done with x"
+  deallocate(x)
+
+  deallocate(y)
+  allocate(y(2 * n))
+  deallocate(y)
+
+  deallocate(z, stat=status)
+  deallocate(z)
+
+  deallocate(p)
+  ! p is finished with
+  deallocate(P)
+end subroutine cleanup
@@ -0,0 +1,21 @@
+module config
+  implicit none (type, external)
+contains
+  subroutine read_config(filename)
+    character(len=*), intent(in) :: filename
+    integer :: unit, iostat
+    open(newunit=unit, file=filename, iostat=iostat)
+    if (iostat /= 0) stop 1
+    close(unit)
+    if (len(filename) == 0) STOP 2
+    if (len(filename) > 100) stop 0
+    if (len(filename) > 200) stop "filename too long"
+  end subroutine read_config
+end module config
+
+program main
+  use config, only: read_config
+  implicit none (type, external)
+  call read_config("input.txt")
+  stop 1
+end program main
@@ -0,0 +1,16 @@
+module scaling
+  implicit none (type, external)
+contains
+  real function scale(r) result(r)
+    real, intent(in) :: r
+  end function scale
+
+  real function scale_by(x, FACTOR) result(factor)
+    real, intent(in) :: x, FACTOR
+  end function scale_by
+
+  real function double(x) result(r)
+    real, intent(in) :: x
+    r = 2 * x
+  end function double
+end module scaling
@@ -0,0 +1,37 @@
+module shapes
+  implicit none (type, external)
+  private
+
+  type, public :: shape
+  end type shape
+
+  type, public, extends(shape) :: circle
+    real :: radius
+  end type circle
+
+  type, public, extends(circle) :: ring
+    real :: inner_radius
+  end type ring
+
+contains
+
+  subroutine draw(s)
+    class(shape), intent(in) :: s
+
+    select type (s)
+    type is (shape)
+      print *, "outline"
+    type is (ring)
+      print *, "ring", s%inner_radius
+    end select
+
+    select type (s)
+    TYPE IS (Circle)
+      print *, "just a circle"
+    class is (circle)
+      print *, "circle or ring"
+    class default
+      print *, "other"
+    end select
+  end subroutine draw
+end module shapes
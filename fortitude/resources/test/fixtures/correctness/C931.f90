@@ -0,0 +1,14 @@
+program namelist_names
+  implicit none
+  real :: foo, bar
+  integer :: Settings
+  namelist /foo/ bar
+  namelist /nml/ foo /settings/ bar
+contains
+  subroutine read_config(unit)
+    integer, intent(in) :: unit
+    real :: tolerance
+    namelist /config/ tolerance
+    read (unit, nml=config)
+  end subroutine read_config
+end program namelist_names
@@ -0,0 +1,15 @@
+program read_parameters
+  implicit none
+  integer, parameter :: myconst = 3
+  real, parameter :: table(2) = [1.0, 2.0]
+  integer :: n
+  real :: x(2)
+  read (*, *) myconst
+  read (*, *) n, table(1)
+  read (*, *) n, x
+contains
+  subroutine read_local()
+    integer :: myconst
+    read (*, *) myconst
+  end subroutine read_local
+end program read_parameters
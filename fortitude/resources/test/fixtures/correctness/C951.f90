@@ -0,0 +1,33 @@
+module sequence_types
+  use, intrinsic :: iso_c_binding, only: c_float
+  implicit none
+
+  type :: point
+    sequence
+    real :: x, y
+  end type point
+
+  type, bind(c) :: c_point
+    real(c_float) :: x, y
+  end type c_point
+
+  interface
+    subroutine draw(p, q) bind(c)
+      import :: point, c_point
+      type(point), intent(in) :: p
+      type(c_point), intent(in) :: q
+    end subroutine draw
+
+    subroutine plot(p)
+      import :: point
+      type(point), intent(in) :: p
+    end subroutine plot
+  end interface
+
+contains
+
+  subroutine move(p) bind(c, name="move")
+    type(Point), intent(inout) :: p
+    p%x = p%x + 1.0
+  end subroutine move
+end module sequence_types
@@ -0,0 +1,43 @@
+module procedure_pointers
+  implicit none
+
+  abstract interface
+    real function func(x)
+      real, intent(in) :: x
+    end function func
+
+    pure real function pure_func(x)
+      real, intent(in) :: x
+    end function pure_func
+  end interface
+
+contains
+
+  subroutine example()
+    procedure(func), pointer :: ptr
+    procedure(pure_func), pointer :: pure_ptr => null()
+    procedure(), pointer :: any_ptr
+    procedure(external_func), pointer :: other_ptr
+
+    ptr => square
+    ptr => cube
+    pure_ptr => square
+    any_ptr => twice
+    other_ptr => square
+  end subroutine example
+
+  pure real function square(x)
+    real, intent(in) :: x
+    square = x * x
+  end function square
+
+  real function cube(x)
+    real, intent(in) :: x
+    cube = x * x * x
+  end function cube
+
+  elemental real function twice(x)
+    real, intent(in) :: x
+    twice = 2 * x
+  end function twice
+end module procedure_pointers
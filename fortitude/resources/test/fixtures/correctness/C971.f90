@@ -0,0 +1,15 @@
+program mixed_kinds
+  use, intrinsic :: iso_fortran_env, only: real32, real64
+  implicit none
+  real(real32) :: real32_var
+  real(kind=real64) :: real64_var
+  real(8) :: legacy_var
+  integer :: n
+
+  real32_var = real32_var + 1.0_real64
+  real64_var = real64_var + 1.0_real64
+  real64_var = 2.5e-3_real32 * real64_var
+  real64_var = legacy_var / 2.0_real64
+  real32_var = real32_var - 1.0
+  real64_var = n + 1.0_real32
+end program mixed_kinds
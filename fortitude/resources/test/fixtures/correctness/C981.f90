@@ -0,0 +1,28 @@
+module solver
+  use, intrinsic :: iso_fortran_env, only: error_unit
+  implicit none
+contains
+  subroutine solve(x, unit)
+    real, intent(inout) :: x
+    integer, intent(in) :: unit
+    if (x < 0) write (ERROR_UNIT, *) 'error'
+    write (0, *) 'x is ', x
+    write (unit, *) 'solving'
+  end subroutine solve
+end module solver
+
+module logging
+  use, intrinsic :: iso_fortran_env, only: stderr => error_unit
+  implicit none
+contains
+  subroutine log_error(message)
+    character(*), intent(in) :: message
+    write (stderr, '(a)') message
+  end subroutine log_error
+end module logging
+
+program main
+  use, intrinsic :: iso_fortran_env, only: error_unit
+  implicit none
+  write (error_unit, *) 'programs can write to stderr'
+end program main
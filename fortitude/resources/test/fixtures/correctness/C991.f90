@@ -0,0 +1,35 @@
+module optional_arguments
+  implicit none
+contains
+  pure real function scale(x, factor)
+    real, intent(in) :: x
+    real, intent(in), optional :: factor
+    scale = x * factor
+  end function scale
+
+  pure real function guarded(x, factor)
+    real, intent(in) :: x
+    real, intent(in), optional :: factor
+    if (present(factor)) then
+      guarded = x * factor
+    else
+      guarded = x
+    end if
+  end function guarded
+
+  elemental subroutine shift(x, offset, big)
+    real, intent(inout) :: x
+    real, intent(in), optional :: offset
+    logical, intent(in), optional :: big
+    x = scale(x, offset)
+    if (.not. present(offset)) return
+    x = x + offset
+    if (BIG) x = x * 2
+  end subroutine shift
+
+  real function impure_scale(x, factor)
+    real, intent(in) :: x
+    real, intent(in), optional :: factor
+    impure_scale = x * factor
+  end function impure_scale
+end module optional_arguments
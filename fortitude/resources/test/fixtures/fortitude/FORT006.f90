@@ -0,0 +1,8 @@
+program test
+  implicit none
+  integer :: i  ! noqa: S101
+  i = 1  ! NOQA
+  ! allow(S101)
+  i = 2
+  i = 3  ! this is not a noqa comment
+end program test
@@ -0,0 +1,7 @@
+! allow(T001, S061,)
+program foo
+  ! allow(literal-kind)
+  real(8) :: x
+  ! allow(star-kind , )
+  real*8 :: y
+end program
@@ -0,0 +1,6 @@
+      program test
+      implicit none
+      include 'header.h'
+  include 'bad.h'
+$include: 'dollar.h'
+      end program test
@@ -0,0 +1,13 @@
+module counters
+  implicit none (type, external)
+  integer :: total
+  !$omp threadprivate(total)
+end module counters
+
+subroutine count_hits()
+  implicit none (type, external)
+  integer :: hits, misses
+  common /tallies/ hits, misses
+  !$OMP THREADPRIVATE(/tallies/)
+  hits = hits + 1
+end subroutine count_hits
@@ -0,0 +1,10 @@
+subroutine build_type(count, lengths, displacements, types, new_type)
+  use mpi
+  implicit none (type, external)
+  integer, intent(in) :: count, lengths(count), types(count)
+  integer(kind=MPI_ADDRESS_KIND), intent(in) :: displacements(count)
+  integer, intent(out) :: new_type
+  integer :: ierr
+  call MPI_Type_struct(count, lengths, displacements, types, new_type, ierr)
+  call MPI_Type_create_struct(count, lengths, displacements, types, new_type, ierr)
+end subroutine build_type
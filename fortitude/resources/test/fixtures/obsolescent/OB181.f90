@@ -0,0 +1,19 @@
+module legacy
+  use mpi
+  implicit none (type, external)
+end module legacy
+
+module also_legacy
+  use MPI, only: MPI_COMM_WORLD
+  implicit none (type, external)
+end module also_legacy
+
+module modern
+  use mpi_f08
+  implicit none (type, external)
+end module modern
+
+module lookalike
+  use mpi_utils
+  implicit none (type, external)
+end module lookalike
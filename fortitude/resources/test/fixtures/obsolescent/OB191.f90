@@ -0,0 +1,13 @@
+subroutine report(x, n)
+  implicit none (type, external)
+  real, intent(in) :: x
+  integer, intent(in) :: n
+  write (*, 100) x
+100 format (F10.3)
+  write (*, 200) n
+  write (*, fmt=200) n + 1
+200 format (I5)
+  print 300, n
+300 format ('n = ', I5)
+  write (*, '(F10.3)') x
+end subroutine report
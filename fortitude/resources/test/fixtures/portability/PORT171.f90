@@ -0,0 +1,7 @@
+subroutine report(unit)
+  implicit none (type, external)
+  integer, intent(in) :: unit
+  write (unit, *) "progress"
+  flush (unit)
+  rewind (unit)
+end subroutine report
@@ -0,0 +1,14 @@
+subroutine report(x)
+  implicit none (type, external)
+  real, intent(in) :: x
+  write (*, 100) x
+100 format (F10.3)
+  write (*, '(F10.3)') x
+  write (*, fmt=200) x
+end subroutine report
+subroutine report_count(i)
+  implicit none (type, external)
+  integer, intent(in) :: i
+  write (*, 200) i
+200 format (I5)
+end subroutine report_count
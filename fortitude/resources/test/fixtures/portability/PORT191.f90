@@ -0,0 +1,6 @@
+program test
+  implicit none
+  include 'common\constants.inc'
+  include "lib\io\units.inc"
+  include 'common/types.inc'
+end program test
@@ -0,0 +1,12 @@
+subroutine read_data(filename)
+  implicit none
+  character(len=*), intent(in) :: filename
+  integer :: input, output
+
+  open(newunit=input, file=filename, action="read")
+  open(unit=20, file="output.txt", action="write")
+  output = 20
+  OPEN(file="log.txt", NEWUNIT=output)
+  close(input)
+  close(output)
+end subroutine read_data
@@ -0,0 +1,9 @@
+subroutine report(x)
+  use, intrinsic :: iso_fortran_env, only: output_unit
+  implicit none (type, external)
+  real, intent(in) :: x
+  write(*, *) x
+  write(unit=*, fmt="(f8.3)") x
+  write(output_unit, *) x
+  print *, x
+end subroutine report
@@ -0,0 +1,13 @@
+program file_paths
+  implicit none
+  integer :: unit
+  character(len=256) :: filename
+  open (newunit=unit, file='/home/user/data.txt')
+  open (newunit=unit, file="~/data.txt")
+  open (newunit=unit, file='C:\data\file.txt')
+  open (newunit=unit, file="data\file.txt")
+  open (newunit=unit, file="data/file.txt")
+  open (newunit=unit, file="file.txt")
+  call get_command_argument(1, filename)
+  open (newunit=unit, file=trim(filename))
+end program file_paths
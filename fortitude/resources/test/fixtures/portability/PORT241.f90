@@ -0,0 +1,12 @@
+module c_kinds
+  use, intrinsic :: iso_c_binding, only: c_double, c_float128, c_long_double, c_int128_t
+  implicit none
+  real(c_float128) :: quad
+  real(kind=c_long_double) :: extended
+  real(c_double) :: double
+  integer(c_int128_t) :: big
+contains
+  subroutine check_kinds()
+    if (c_int128_t < 0) error stop "128-bit integers are not supported"
+  end subroutine check_kinds
+end module c_kinds
@@ -0,0 +1,17 @@
+program test
+  implicit none
+  integer :: i
+  Integer :: j
+  INTEGER :: k
+  i = 1
+  if (i > 0) then
+    j = 2
+  end if
+  IF (j > 0) k = 3
+  do i = 1, 2
+    j = i
+  end do
+  do i = 1, 2
+    k = i
+  enddo
+end program test
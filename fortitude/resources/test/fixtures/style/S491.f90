@@ -0,0 +1,14 @@
+program test
+  implicit none
+  real :: a, b, c, x
+  a = 1.0
+  b = 2.0
+  c = 3.0
+  x = a +b*c
+  x = a + b * c
+  x = a+ b
+  x = a + &
+      b
+  if (x == a) x = b
+  if (x==a) x = c
+end program test
@@ -0,0 +1,8 @@
+program test
+  implicit none
+  real :: a, b, x
+  a = 1.0
+  b = 2.0
+  x = a + b
+  x = a*b
+end program test
@@ -0,0 +1,17 @@
+module test
+  implicit none
+contains
+  subroutine foo()
+    integer :: i
+
+
+
+    i = 1
+  end subroutine foo
+
+  subroutine bar()
+    integer :: i
+
+    i = 1
+  end subroutine bar
+end module test
@@ -0,0 +1,17 @@
+module foo
+  implicit none (type, external)
+contains
+end module foo
+
+module bar
+  implicit none (type, external)
+contains
+  subroutine baz()
+  end subroutine baz
+end module bar
+
+subroutine outer()
+  implicit none (type, external)
+  CONTAINS
+  ! nothing here yet
+end subroutine outer
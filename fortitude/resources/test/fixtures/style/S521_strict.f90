@@ -0,0 +1,17 @@
+program continuation
+  implicit none (type, external)
+  real :: total
+  total = combine(1.0, 2.0, &
+                  3.0, 4.0)
+  total = combine(1.0, 2.0, &
+      3.0, 4.0)
+  total = combine(1.0, 2.0, &
+  3.0, 4.0)
+  total = combine( &
+    1.0, 2.0, 3.0, 4.0)
+contains
+  real function combine(a, b, c, d)
+    real, intent(in) :: a, b, c, d
+    combine = a + b + c + d
+  end function combine
+end program continuation
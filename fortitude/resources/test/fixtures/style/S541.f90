@@ -0,0 +1,13 @@
+subroutine late()
+  implicit none (type, external)
+  real :: x
+  use, intrinsic :: iso_fortran_env, only: real64
+  x = 1.0
+end subroutine late
+
+subroutine early()
+  use, intrinsic :: iso_fortran_env, only: real64
+  implicit none (type, external)
+  real :: x
+  x = 1.0
+end subroutine early
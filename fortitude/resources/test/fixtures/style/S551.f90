@@ -0,0 +1,17 @@
+module mymod
+  implicit none (type, external)
+  type :: point
+    real :: x, y
+  end type vector
+contains
+  subroutine foo()
+  end subroutine bar
+  integer function answer()
+    answer = 42
+  end function ANSWER
+end module mymod
+
+program myprog
+  use mymod
+  implicit none (type, external)
+end program other
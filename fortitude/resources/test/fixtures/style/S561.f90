@@ -0,0 +1,64 @@
+module test
+  implicit none
+  private
+  public :: opaque_t, counter_t, handle_t, point_t, locked_t
+
+  type :: opaque_t
+    private
+    integer :: value
+  end type opaque_t
+
+  type :: counter_t
+    private
+    integer :: count = 0
+  contains
+    procedure :: increment
+  end type counter_t
+
+  type :: handle_t
+    private
+    integer :: id
+  end type handle_t
+
+  interface handle_t
+    module procedure new_handle
+  end interface handle_t
+
+  type :: point_t
+    private
+    real, public :: x, y
+    integer :: tag
+  end type point_t
+
+  type, private :: internal_t
+    private
+    integer :: data
+  end type internal_t
+
+  type :: locked_t
+    private
+    integer :: state
+  contains
+    private
+    procedure :: unlock
+  end type locked_t
+
+contains
+
+  subroutine increment(self)
+    class(counter_t), intent(inout) :: self
+    self%count = self%count + 1
+  end subroutine increment
+
+  function new_handle(id) result(handle)
+    integer, intent(in) :: id
+    type(handle_t) :: handle
+    handle%id = id
+  end function new_handle
+
+  subroutine unlock(self)
+    class(locked_t), intent(inout) :: self
+    self%state = 0
+  end subroutine unlock
+
+end module test
@@ -0,0 +1,8 @@
+module test
+  implicit none
+  real, parameter :: a = 1.0, b
+  integer, parameter, private :: n = 3, m, k
+  real, parameter :: c
+  real, parameter :: d = 2.0
+  real :: e, f
+end module test
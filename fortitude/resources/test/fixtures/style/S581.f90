@@ -0,0 +1,12 @@
+program test
+  implicit real (a-h, o-z)
+  x = 1.0
+end program test
+
+subroutine counts()
+  implicit integer (i-n)
+end subroutine counts
+
+subroutine explicit()
+  implicit none
+end subroutine explicit
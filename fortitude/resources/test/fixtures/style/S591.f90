@@ -0,0 +1,15 @@
+module solver
+  use constants, only: pi
+  use utilities
+  implicit none (type, external)
+contains
+  subroutine step()
+    use constants
+    use, intrinsic :: iso_fortran_env
+  end subroutine step
+
+  subroutine report()
+    use utilities
+    use, intrinsic :: iso_fortran_env, only: output_unit
+  end subroutine report
+end module solver
@@ -0,0 +1,33 @@
+module shapes
+  use geometry, only: triangle_area
+  implicit none (type, external)
+  private
+  public :: area
+
+  interface area
+    module procedure circle_area, square_aera
+    module procedure triangle_area
+  end interface area
+
+contains
+
+  real function circle_area(r)
+    real, intent(in) :: r
+    circle_area = 3.14159 * r**2
+  end function circle_area
+
+  real function square_area(s)
+    real, intent(in) :: s
+    square_area = s**2
+  end function square_area
+
+end module shapes
+
+module everything
+  use geometry
+  implicit none (type, external)
+
+  interface area
+    module procedure hexagon_area
+  end interface area
+end module everything
@@ -0,0 +1,8 @@
+program test
+  implicit none  ! two spaces
+  integer :: x ! one space
+  integer :: y      ! six spaces
+  ! own line
+  x = 1!none
+  y = 2  ! two spaces
+end program test
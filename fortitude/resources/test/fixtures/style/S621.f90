@@ -0,0 +1,34 @@
+subroutine classify(i, n)
+  implicit none (type, external)
+  integer, intent(in) :: i, n
+
+  select case (i)
+  case (1:5)
+    print *, "small"
+  case (3)
+    print *, "three"
+  case (4:5, 6)
+    print *, "four to six"
+  case (10:)
+    print *, "large"
+  case (-2, 100)
+    print *, "very large"
+  case (:-1)
+    print *, "negative"
+  case (-2)
+    print *, "minus two"
+  case default
+    print *, "other"
+  end select
+
+  select case (i)
+  case (1:5)
+    print *, "small"
+  case (6:9)
+    print *, "medium"
+  case (n)
+    print *, "n"
+  case (n:10)
+    print *, "up to ten"
+  end select
+end subroutine classify
@@ -0,0 +1,34 @@
+subroutine many()
+  implicit none (type, external)
+  call one()
+contains
+  subroutine one()
+  end subroutine one
+  subroutine two()
+  end subroutine two
+  subroutine three()
+  end subroutine three
+  subroutine four()
+  end subroutine four
+  subroutine five()
+  end subroutine five
+  subroutine six()
+  end subroutine six
+  subroutine seven()
+  end subroutine seven
+end subroutine many
+
+integer function few()
+  implicit none (type, external)
+  few = first() + second() + third()
+contains
+  integer function first()
+    first = 1
+  end function first
+  integer function second()
+    second = 1
+  end function second
+  integer function third()
+    third = 1
+  end function third
+end function few
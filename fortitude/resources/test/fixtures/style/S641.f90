@@ -0,0 +1,24 @@
+module kinds
+  use, intrinsic :: iso_fortran_env
+  implicit none (type, external)
+  real(8) :: x
+  real(real64) :: y
+  integer(kind=4) :: i
+contains
+  subroutine renamed()
+    use, intrinsic :: iso_fortran_env, only: dp => real64
+    real(8) :: a
+  end subroutine renamed
+end module kinds
+
+module other_kinds
+  use, intrinsic :: iso_fortran_env, only: int32
+  implicit none (type, external)
+  real(8) :: x
+  integer(4) :: i
+end module other_kinds
+
+module no_kinds
+  implicit none (type, external)
+  real(8) :: x
+end module no_kinds
@@ -0,0 +1,18 @@
+subroutine late()
+  implicit none (type, external)
+  real :: x
+  x = 1.0
+  real :: y
+  y = 2.0 * x
+end subroutine late
+
+subroutine early()
+  implicit none (type, external)
+  real :: x, y
+  x = 1.0
+  y = 2.0 * x
+  block
+    real :: z
+    z = x + y
+  end block
+end subroutine early
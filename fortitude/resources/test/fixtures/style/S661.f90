@@ -0,0 +1,14 @@
+module todo_comments
+  implicit none
+  ! This is a regular comment
+  ! TODO: fix this
+  integer :: x  ! fixme later
+  !HACK
+  ! Todos are not markers
+  ! note: this is a note
+contains
+  subroutine foo()
+    !   XXX: check bounds
+    ! debugging is fine
+  end subroutine foo
+end module todo_comments
@@ -0,0 +1,7 @@
+program custom_markers
+  implicit none
+  ! TODO: not reported with custom markers
+  ! OPTIMIZE: vectorise this loop
+  ! optimized already
+  print *, "hello"  ! REVIEW before merging
+end program custom_markers
@@ -0,0 +1,39 @@
+program do_loops
+  implicit none
+  integer :: i, j
+  real :: x(10), y(10, 10)
+
+  do i = 1, 10
+    x(i) = 0.0
+  end do
+
+  do i = 1, 10
+    print *, i
+  enddo
+
+  ! Nested loops and multiple statements are fine
+  do j = 1, 10
+    do i = 1, 10
+      y(i, j) = x(i) * x(j) + real(i + j) / 2.0 - 1.0
+    end do
+  end do
+
+  do i = 1, 10
+    x(i) = 1.0
+    print *, x(i)
+  end do
+
+  ! So are labelled and named loops
+  do 10 i = 1, 10
+    x(i) = 2.0
+10 continue
+
+  outer: do i = 1, 10
+    x(i) = 3.0
+  end do outer
+
+  do i = 1, 10
+    ! Comments are kept
+    x(i) = 4.0
+  end do
+end program do_loops
@@ -0,0 +1,11 @@
+module use_renames
+  use mesh, only: nodes => points
+  use mesh, only: vertices => Points, edges => lines
+  use mesh, only: sides => lines, lines
+  use geometry, only: corners => points
+  implicit none
+contains
+  subroutine renamed_once()
+    use mesh, only: pts => points, segments => lines
+  end subroutine renamed_once
+end module use_renames
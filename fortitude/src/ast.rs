@@ -222,3 +222,106 @@ pub fn is_keyword_argument<S: AsRef<str>>(node: &Node, keyword: S, src: &str) ->
     }
     false
 }
+
+/// Node kinds that introduce a new scope for variable declarations.
+pub const SCOPING_UNITS: &[&str] = &[
+    "program",
+    "module",
+    "submodule",
+    "function",
+    "subroutine",
+    "module_procedure",
+];
+
+/// Returns the innermost program unit or procedure that contains `node`.
+pub fn scoping_unit<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut ancestor = node.parent();
+    while let Some(scope) = ancestor {
+        if SCOPING_UNITS.contains(&scope.kind()) {
+            return Some(scope);
+        }
+        ancestor = scope.parent();
+    }
+    None
+}
+
+/// Returns the name of the variable introduced by a declarator, such as `x` in
+/// `integer :: x(10)` or `real :: x = 1.0`.
+pub fn declarator_name<'a>(declarator: &Node, src: &'a str) -> Option<&'a str> {
+    match declarator.kind() {
+        "identifier" => declarator.to_text(src),
+        "sized_declarator" => declarator.child_with_name("identifier")?.to_text(src),
+        "init_declarator" | "pointer_init_declarator" => {
+            declarator_name(&declarator.child_by_field_name("left")?, src)
+        }
+        _ => None,
+    }
+}
+
+/// Find the `variable_declaration` that declares `name`, searching outwards
+/// from the innermost scoping unit containing `node` through any host scopes.
+/// Comparison is case-insensitive.
+pub fn find_variable_declaration<'a>(node: &Node<'a>, name: &str, src: &str) -> Option<Node<'a>> {
+    let name = name.to_lowercase();
+    let mut scope = scoping_unit(node);
+    while let Some(unit) = scope {
+        let declaration = unit
+            .named_children(&mut unit.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .find(|decl| {
+                decl.children_by_field_name("declarator", &mut decl.walk())
+                    .filter_map(|declarator| declarator_name(&declarator, src))
+                    .any(|declared| declared.to_lowercase() == name)
+            });
+        if declaration.is_some() {
+            return declaration;
+        }
+        scope = scoping_unit(&unit);
+    }
+    None
+}
+
+/// Returns the lowercase intrinsic type of a `variable_declaration`, such as
+/// `integer` or `real`, or `None` if it is a derived type.
+pub fn declaration_type(declaration: &Node, src: &str) -> Option<String> {
+    let type_ = declaration.child_by_field_name("type")?;
+    if type_.kind() != "intrinsic_type" {
+        return None;
+    }
+    Some(type_.to_text(src)?.to_lowercase())
+}
+
+/// Returns true if `declaration` has the attribute `attribute`, such as
+/// `allocatable` or `pointer`. Comparison is case-insensitive.
+pub fn has_attribute(declaration: &Node, attribute: &str, src: &str) -> bool {
+    declaration
+        .children_by_field_name("attribute", &mut declaration.walk())
+        .filter_map(|child| child.to_text(src))
+        .any(|child| child.eq_ignore_ascii_case(attribute))
+}
+
+/// Returns true if `declaration` declares `name` as an array, either through a
+/// `dimension` attribute or an array declarator such as `x(10)`.
+pub fn declares_array(declaration: &Node, name: &str, src: &str) -> bool {
+    let has_dimension = declaration
+        .children_by_field_name("attribute", &mut declaration.walk())
+        .filter_map(|attribute| attribute.to_text(src))
+        .any(|attribute| attribute.to_lowercase().starts_with("dimension"));
+    if has_dimension {
+        return true;
+    }
+
+    let name = name.to_lowercase();
+    declaration
+        .children_by_field_name("declarator", &mut declaration.walk())
+        .filter(|declarator| {
+            declarator_name(declarator, src).is_some_and(|declared| declared.to_lowercase() == name)
+        })
+        .any(|declarator| {
+            let mut declarator = declarator;
+            while let Some(left) = declarator.child_by_field_name("left") {
+                declarator = left;
+            }
+            declarator.kind() == "sized_declarator"
+        })
+}
@@ -14,8 +14,9 @@ use crate::rules::error::allow_comments::InvalidRuleCodeOrName;
 use crate::rules::Rule;
 use crate::rules::{error::ioerror::IoError, AstRuleEnum, PathRuleEnum, TextRuleEnum};
 use crate::settings::{
-    ExcludeMode, FilePattern, FilePatternSet, FixMode, GitignoreMode, OutputFormat,
-    PatternPrefixPair, PreviewMode, ProgressBar, Settings, UnsafeFixes, DEFAULT_SELECTORS,
+    ExcludeMode, FilePattern, FilePatternSet, FixMode, FortranStandard, GitignoreMode,
+    MaxCommentSpaces, NamePatternSet, OperatorSpacing, OutputFormat, PatternPrefixPair,
+    PreviewMode, ProgressBar, Settings, SummaryMode, UnsafeFixes, DEFAULT_SELECTORS,
 };
 
 use anyhow::{anyhow, Context, Result};
@@ -23,7 +24,7 @@ use colored::Colorize;
 use ignore::{types::TypesBuilder, WalkBuilder};
 use indicatif::{ParallelProgressIterator, ProgressStyle};
 use itertools::Itertools;
-use lazy_regex::{regex, regex_captures};
+use lazy_regex::regex;
 use log::{debug, warn};
 use rayon::prelude::*;
 use ruff_diagnostics::Diagnostic;
@@ -35,7 +36,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use std::str::FromStr;
@@ -66,6 +67,18 @@ struct CheckSection {
     check: Option<CheckArgs>,
 }
 
+// Similarly, these let us work out if there's a fortitude section in
+// a pyproject.toml file
+#[derive(Debug, PartialEq, Eq, Default, Deserialize)]
+struct Pyproject {
+    tool: Option<Tool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Default, Deserialize)]
+struct Tool {
+    fortitude: Option<CheckSection>,
+}
+
 // Default paths to exclude when searching paths
 pub(crate) static EXCLUDE_BUILTINS: &[FilePattern] = &[
     FilePattern::Builtin(".git"),
@@ -98,17 +111,29 @@ pub fn fortitude_enabled<P: AsRef<Path>>(path: P) -> Result<bool> {
     Ok(fpm.extra.and_then(|extra| extra.fortitude).is_some())
 }
 
-/// Return the path to the `fpm.toml` or `fortitude.toml` file in a given
-/// directory. Adapated from ruff
+fn parse_pyproject_toml<P: AsRef<Path>>(path: P) -> Result<Pyproject> {
+    let contents = std::fs::read_to_string(path.as_ref())
+        .with_context(|| format!("Failed to read {}", path.as_ref().display()))?;
+    toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.as_ref().display()))
+}
+
+pub fn pyproject_fortitude_enabled<P: AsRef<Path>>(path: P) -> Result<bool> {
+    let pyproject = parse_pyproject_toml(path)?;
+    Ok(pyproject.tool.and_then(|tool| tool.fortitude).is_some())
+}
+
+/// Return the path to the `fortitude.toml`, `fpm.toml`, or `pyproject.toml`
+/// file in a given directory. Adapated from ruff
 pub fn settings_toml<P: AsRef<Path>>(path: P) -> Result<Option<PathBuf>> {
-    // Check for `.fortitude.toml`.
-    let fortitude_toml = path.as_ref().join(".fortitude.toml");
+    // Check for `fortitude.toml`.
+    let fortitude_toml = path.as_ref().join("fortitude.toml");
     if fortitude_toml.is_file() {
         return Ok(Some(fortitude_toml));
     }
 
-    // Check for `fortitude.toml`.
-    let fortitude_toml = path.as_ref().join("fortitude.toml");
+    // Check for `.fortitude.toml`.
+    let fortitude_toml = path.as_ref().join(".fortitude.toml");
     if fortitude_toml.is_file() {
         return Ok(Some(fortitude_toml));
     }
@@ -119,11 +144,17 @@ pub fn settings_toml<P: AsRef<Path>>(path: P) -> Result<Option<PathBuf>> {
         return Ok(Some(fpm_toml));
     }
 
+    // Check for `pyproject.toml`.
+    let pyproject_toml = path.as_ref().join("pyproject.toml");
+    if pyproject_toml.is_file() && pyproject_fortitude_enabled(&pyproject_toml)? {
+        return Ok(Some(pyproject_toml));
+    }
+
     Ok(None)
 }
 
-/// Find the path to the `fpm.toml` or `fortitude.toml` file, if such a file
-/// exists. Adapated from ruff
+/// Find the path to the `fortitude.toml`, `fpm.toml`, or `pyproject.toml`
+/// file, if such a file exists. Adapated from ruff
 pub fn find_settings_toml<P: AsRef<Path>>(path: P) -> Result<Option<PathBuf>> {
     for directory in path.as_ref().ancestors() {
         if let Some(settings) = settings_toml(directory)? {
@@ -133,8 +164,9 @@ pub fn find_settings_toml<P: AsRef<Path>>(path: P) -> Result<Option<PathBuf>> {
     Ok(None)
 }
 
-/// Read either the "extra.fortitude" table from "fpm.toml", or the
-/// whole "fortitude.toml" file
+/// Read either the "extra.fortitude" table from "fpm.toml", the
+/// "tool.fortitude" table from "pyproject.toml", or the whole
+/// "fortitude.toml" file
 fn from_toml_subsection<P: AsRef<Path>>(path: P) -> Result<CheckSection> {
     let config_str = if path.as_ref().ends_with("fpm.toml") {
         let config = std::fs::read_to_string(path)?.parse::<Table>()?;
@@ -144,6 +176,13 @@ fn from_toml_subsection<P: AsRef<Path>>(path: P) -> Result<CheckSection> {
         let extra = &config["extra"].as_table().unwrap();
         let fortitude = &extra["fortitude"].as_table().unwrap();
         fortitude.to_string()
+    } else if path.as_ref().ends_with("pyproject.toml") {
+        let config = std::fs::read_to_string(path)?.parse::<Table>()?;
+
+        // As above, we've already checked this file has these tables
+        let tool = &config["tool"].as_table().unwrap();
+        let fortitude = &tool["fortitude"].as_table().unwrap();
+        fortitude.to_string()
     } else {
         std::fs::read_to_string(path)?
     };
@@ -175,15 +214,32 @@ pub struct CheckSettings {
     pub extend_select: Vec<RuleSelector>,
     pub per_file_ignores: Option<Vec<PatternPrefixPair>>,
     pub extend_per_file_ignores: Vec<PatternPrefixPair>,
+    pub ignore_allow_comments: bool,
     pub line_length: usize,
+    pub operator_spacing: OperatorSpacing,
+    pub require_real_leading_zero: bool,
+    pub allowed_volatile_names: Vec<String>,
+    pub strict_continuation_alignment: bool,
+    pub coarray_support: bool,
+    pub min_comment_spaces: usize,
+    pub max_comment_spaces: MaxCommentSpaces,
+    pub prefer_mpi_f08: bool,
+    pub max_internal_procedures: usize,
+    pub require_explicit_output_unit: bool,
+    pub todo_markers: Vec<String>,
+    pub max_do_single_line_length: usize,
+    pub allowed_logging_modules: Vec<String>,
     pub file_extensions: Vec<String>,
     pub fix: bool,
     pub fix_only: bool,
     pub show_fixes: bool,
     pub unsafe_fixes: UnsafeFixes,
     pub output_format: OutputFormat,
+    pub output_file: Option<PathBuf>,
+    pub summary: Option<SummaryMode>,
     pub progress_bar: ProgressBar,
     pub preview: PreviewMode,
+    pub target_std: FortranStandard,
     pub exclude: Option<Vec<FilePattern>>,
     pub extend_exclude: Vec<FilePattern>,
     pub exclude_mode: ExcludeMode,
@@ -199,15 +255,32 @@ impl Default for CheckSettings {
             extend_select: Default::default(),
             per_file_ignores: Default::default(),
             extend_per_file_ignores: Default::default(),
+            ignore_allow_comments: Settings::default().ignore_allow_comments,
             line_length: Settings::default().line_length,
+            operator_spacing: Settings::default().operator_spacing,
+            require_real_leading_zero: Settings::default().require_real_leading_zero,
+            allowed_volatile_names: Default::default(),
+            strict_continuation_alignment: Settings::default().strict_continuation_alignment,
+            coarray_support: Settings::default().coarray_support,
+            min_comment_spaces: Settings::default().min_comment_spaces,
+            max_comment_spaces: Settings::default().max_comment_spaces,
+            prefer_mpi_f08: Settings::default().prefer_mpi_f08,
+            max_internal_procedures: Settings::default().max_internal_procedures,
+            require_explicit_output_unit: Settings::default().require_explicit_output_unit,
+            todo_markers: Settings::default().todo_markers,
+            max_do_single_line_length: Settings::default().max_do_single_line_length,
+            allowed_logging_modules: Default::default(),
             file_extensions: FORTRAN_EXTS.iter().map(|ext| ext.to_string()).collect(),
             fix: Default::default(),
             fix_only: Default::default(),
             show_fixes: Default::default(),
             unsafe_fixes: Default::default(),
             output_format: Default::default(),
+            output_file: Default::default(),
+            summary: Default::default(),
             progress_bar: Default::default(),
             preview: Default::default(),
+            target_std: Settings::default().target_std,
             exclude: Default::default(),
             extend_exclude: Default::default(),
             exclude_mode: Default::default(),
@@ -216,8 +289,8 @@ impl Default for CheckSettings {
     }
 }
 
-/// Read either fpm.toml or fortitude.toml into our "known good" file
-/// settings struct
+/// Read either fpm.toml, pyproject.toml, or fortitude.toml into our
+/// "known good" file settings struct
 fn parse_config_file(config_file: &Option<PathBuf>) -> Result<CheckSettings> {
     let filename = match config_file {
         Some(filename) => filename.clone(),
@@ -237,7 +310,51 @@ fn parse_config_file(config_file: &Option<PathBuf>) -> Result<CheckSettings> {
             extend_select: value.extend_select.unwrap_or_default(),
             per_file_ignores: value.per_file_ignores,
             extend_per_file_ignores: value.extend_per_file_ignores.unwrap_or_default(),
+            ignore_allow_comments: resolve_bool_arg(
+                value.ignore_allow_comments,
+                value.no_ignore_allow_comments,
+            )
+            .unwrap_or(Settings::default().ignore_allow_comments),
             line_length: value.line_length.unwrap_or(Settings::default().line_length),
+            operator_spacing: value
+                .operator_spacing
+                .unwrap_or(Settings::default().operator_spacing),
+            require_real_leading_zero: resolve_bool_arg(
+                value.require_real_leading_zero,
+                value.no_require_real_leading_zero,
+            )
+            .unwrap_or(Settings::default().require_real_leading_zero),
+            allowed_volatile_names: value.allowed_volatile_names.unwrap_or_default(),
+            strict_continuation_alignment: resolve_bool_arg(
+                value.strict_continuation_alignment,
+                value.no_strict_continuation_alignment,
+            )
+            .unwrap_or(Settings::default().strict_continuation_alignment),
+            coarray_support: resolve_bool_arg(value.coarray_support, value.no_coarray_support)
+                .unwrap_or(Settings::default().coarray_support),
+            min_comment_spaces: value
+                .min_comment_spaces
+                .unwrap_or(Settings::default().min_comment_spaces),
+            max_comment_spaces: value
+                .max_comment_spaces
+                .unwrap_or(Settings::default().max_comment_spaces),
+            prefer_mpi_f08: resolve_bool_arg(value.prefer_mpi_f08, value.no_prefer_mpi_f08)
+                .unwrap_or(Settings::default().prefer_mpi_f08),
+            max_internal_procedures: value
+                .max_internal_procedures
+                .unwrap_or(Settings::default().max_internal_procedures),
+            require_explicit_output_unit: resolve_bool_arg(
+                value.require_explicit_output_unit,
+                value.no_require_explicit_output_unit,
+            )
+            .unwrap_or(Settings::default().require_explicit_output_unit),
+            todo_markers: value
+                .todo_markers
+                .unwrap_or(Settings::default().todo_markers),
+            max_do_single_line_length: value
+                .max_do_single_line_length
+                .unwrap_or(Settings::default().max_do_single_line_length),
+            allowed_logging_modules: value.allowed_logging_modules.unwrap_or_default(),
             file_extensions: value
                 .file_extensions
                 .unwrap_or(FORTRAN_EXTS.iter().map(|ext| ext.to_string()).collect_vec()),
@@ -248,10 +365,13 @@ fn parse_config_file(config_file: &Option<PathBuf>) -> Result<CheckSettings> {
                 .map(UnsafeFixes::from)
                 .unwrap_or_default(),
             output_format: value.output_format.unwrap_or_default(),
+            output_file: value.output_file,
+            summary: value.summary,
             progress_bar: value.progress_bar.unwrap_or_default(),
             preview: resolve_bool_arg(value.preview, value.no_preview)
                 .map(PreviewMode::from)
                 .unwrap_or_default(),
+            target_std: value.target_std.unwrap_or(Settings::default().target_std),
             exclude: value.exclude,
             extend_exclude: value.extend_exclude.unwrap_or_default(),
             exclude_mode: resolve_bool_arg(value.force_exclude, value.no_force_exclude)
@@ -471,7 +591,8 @@ struct AllowComment {
     pub range: TextRange,
 }
 
-/// If this node is an `allow` comment, get all the rules allowed on the next line
+/// If this node is an `allow` or `noqa` comment, get all the rules allowed on the lines it
+/// applies to
 fn gather_allow_comments(
     node: &Node,
     file: &SourceFile,
@@ -481,41 +602,15 @@ fn gather_allow_comments(
         return Ok(vec![]);
     }
 
-    let mut allow_comments = Vec::new();
-    let mut errors = Vec::new();
-
-    if let Some((_, allow_comment)) = regex_captures!(
-        r#"! allow\((.*)\)\s*"#,
-        node.to_text(file.source_text()).unwrap()
-    ) {
-        let preview = PreviewOptions {
-            mode: PreviewMode::Enabled,
-            require_explicit: false,
-        };
+    let text = node.to_text(file.source_text()).unwrap();
+    let comment_start = TextSize::try_from(node.start_byte()).unwrap();
+    let src = file.to_source_code();
 
-        // Partition the found selectors into valid and invalid
-        let rule_regex = regex!(r#"\w[-\w\d]*"#);
-        let mut allow_rules = Vec::new();
-        // 8 from length of "! allow("
-        let comment_start_offset =
-            TextSize::try_from(node.start_byte()).unwrap() + TextSize::new(8);
-        for rule in rule_regex.find_iter(allow_comment) {
-            match RuleSelector::from_str(rule.as_str()) {
-                Ok(rule) => allow_rules.push(rule),
-                Err(error) => {
-                    let start = comment_start_offset + TextSize::try_from(rule.start()).unwrap();
-                    let end = comment_start_offset + TextSize::try_from(rule.end()).unwrap();
-                    errors.push(Diagnostic::new(
-                        InvalidRuleCodeOrName {
-                            message: error.to_string(),
-                        },
-                        TextRange::new(start, end),
-                    ))
-                }
-            }
-        }
-
-        if let Some(next_node) = node.next_named_sibling() {
+    // `allow` comments apply to the next statement, while `noqa` comments apply to the line
+    // they're on. A `noqa` comment without any rules allows everything.
+    let (selectors, range) = if let Some(captures) = regex!(r#"! allow\((.*)\)\s*"#).captures(text)
+    {
+        let range = node.next_named_sibling().map(|next_node| {
             let start_byte = TextSize::try_from(next_node.start_byte()).unwrap();
             let end_byte = TextSize::try_from(next_node.end_byte()).unwrap();
 
@@ -523,19 +618,74 @@ fn gather_allow_comments(
             // line that it _ends_ on -- i.e. including trailing
             // whitespace and other statements. This might have weird
             // edge cases.
-            let src = file.to_source_code();
             let start_index = src.line_index(start_byte);
             let end_index = src.line_index(end_byte);
-            let start_line = src.line_start(start_index);
-            let end_line = src.line_end(end_index);
+            TextRange::new(src.line_start(start_index), src.line_end(end_index))
+        });
+        (captures.get(1), range)
+    } else if let Some(captures) = regex!(r#"(?i)^!\s*noqa(?::\s*(.*))?\s*$"#).captures(text) {
+        // If this is an inline comment, also cover the start of a statement
+        // that spans multiple lines
+        let comment_index = src.line_index(comment_start);
+        let start_index = match node.prev_named_sibling() {
+            Some(prev)
+                if src.line_index(TextSize::try_from(prev.end_byte()).unwrap())
+                    == comment_index =>
+            {
+                src.line_index(TextSize::try_from(prev.start_byte()).unwrap())
+            }
+            _ => comment_index,
+        };
+        let range = TextRange::new(src.line_start(start_index), src.line_end(comment_index));
+        (captures.get(1), Some(range))
+    } else {
+        return Ok(vec![]);
+    };
+
+    let mut allow_comments = Vec::new();
+    let mut errors = Vec::new();
+
+    let preview = PreviewOptions {
+        mode: PreviewMode::Enabled,
+        require_explicit: false,
+    };
 
-            let range = TextRange::new(start_line, end_line);
-            for rule_selector in allow_rules {
-                for rule in rule_selector.rules(&preview) {
-                    allow_comments.push(AllowComment { rule, range });
+    // Partition the found selectors into valid and invalid
+    let allows_all = selectors.is_none();
+    let mut allow_rules = Vec::new();
+    match selectors {
+        Some(selectors) => {
+            let rule_regex = regex!(r#"\w[-\w\d]*"#);
+            let selectors_start = comment_start + TextSize::try_from(selectors.start()).unwrap();
+            for rule in rule_regex.find_iter(selectors.as_str()) {
+                match RuleSelector::from_str(rule.as_str()) {
+                    Ok(rule) => allow_rules.push(rule),
+                    Err(error) => {
+                        let start = selectors_start + TextSize::try_from(rule.start()).unwrap();
+                        let end = selectors_start + TextSize::try_from(rule.end()).unwrap();
+                        errors.push(Diagnostic::new(
+                            InvalidRuleCodeOrName {
+                                message: error.to_string(),
+                            },
+                            TextRange::new(start, end),
+                        ))
+                    }
                 }
             }
-        };
+        }
+        None => allow_rules.push(RuleSelector::All),
+    }
+
+    if let Some(range) = range {
+        for rule_selector in allow_rules {
+            for rule in rule_selector.rules(&preview) {
+                // Don't let a bare `noqa` hide the warning about itself
+                if allows_all && rule == Rule::NoqaComment {
+                    continue;
+                }
+                allow_comments.push(AllowComment { rule, range });
+            }
+        }
     }
 
     if !errors.is_empty() && rules.enabled(Rule::InvalidRuleCodeOrName) {
@@ -545,11 +695,16 @@ fn gather_allow_comments(
     }
 }
 
-/// Filter out allowed rules
-fn filter_allowed_rules(diagnostic: &Diagnostic, allow_comments: &[AllowComment]) -> bool {
-    allow_comments.iter().all(|allow| {
-        !(allow.rule == diagnostic.kind.rule() && allow.range.contains_range(diagnostic.range))
-    })
+/// Filter out allowed rules, unless allow comments are being ignored
+fn filter_allowed_rules(
+    diagnostic: &Diagnostic,
+    allow_comments: &[AllowComment],
+    settings: &Settings,
+) -> bool {
+    settings.ignore_allow_comments
+        || allow_comments.iter().all(|allow| {
+            !(allow.rule == diagnostic.kind.rule() && allow.range.contains_range(diagnostic.range))
+        })
 }
 
 /// Parse a file, check it for issues, and return the report.
@@ -584,7 +739,9 @@ pub(crate) fn check_only_file(
     let tree = parser
         .parse(file.source_text(), None)
         .context("Failed to parse")?;
-    for node in tree.root_node().named_descendants() {
+    // Include the root node so that rules can inspect the whole file
+    let root = tree.root_node();
+    for node in std::iter::once(root).chain(root.named_descendants()) {
         if let Some(rules) = ast_entrypoints.get(node.kind()) {
             for rule in rules {
                 if let Some(violation) = rule.check(settings, &node, file) {
@@ -602,7 +759,7 @@ pub(crate) fn check_only_file(
 
     Ok(violations
         .into_iter()
-        .filter(|diagnostic| filter_allowed_rules(diagnostic, &allow_comments))
+        .filter(|diagnostic| filter_allowed_rules(diagnostic, &allow_comments, settings))
         .map(|v| DiagnosticMessage::from_ruff(file, v))
         .collect_vec())
 }
@@ -674,7 +831,9 @@ pub(crate) fn check_and_fix_file<'a>(
             .context("Failed to parse")?;
 
         // Perform AST analysis
-        for node in tree.root_node().named_descendants() {
+        // Include the root node so that rules can inspect the whole file
+        let root = tree.root_node();
+        for node in std::iter::once(root).chain(root.named_descendants()) {
             if let Some(rules) = ast_entrypoints.get(node.kind()) {
                 for rule in rules {
                     if let Some(violation) = rule.check(settings, &node, &transformed) {
@@ -684,7 +843,7 @@ pub(crate) fn check_and_fix_file<'a>(
                     }
                 }
             }
-            match gather_allow_comments(&node, file, rules) {
+            match gather_allow_comments(&node, &transformed, rules) {
                 Ok(mut allow_rules) => allow_comments.append(&mut allow_rules),
                 Err(mut violation) => violations.append(&mut violation),
             };
@@ -699,7 +858,7 @@ pub(crate) fn check_and_fix_file<'a>(
 
         let violations = violations
             .into_iter()
-            .filter(|diagnostic| filter_allowed_rules(diagnostic, &allow_comments))
+            .filter(|diagnostic| filter_allowed_rules(diagnostic, &allow_comments, settings))
             .collect_vec();
 
         // Apply fix
@@ -880,6 +1039,77 @@ pub(crate) fn ast_entrypoint_map<'a>(rules: &RuleTable) -> BTreeMap<&'a str, Vec
     map
 }
 
+/// Returns `true` if the file to check should be read from stdin, either
+/// because `-` was passed as the only file, or `--stdin-filename` was set.
+fn is_stdin(files: &[PathBuf], stdin_filename: Option<&Path>) -> bool {
+    if stdin_filename.is_some() {
+        for file in files
+            .iter()
+            .filter(|file| !matches!(file.to_str(), Some("." | "-")))
+        {
+            warn!(
+                "Ignoring file {} in favour of standard input",
+                file.display()
+            );
+        }
+        return true;
+    }
+    matches!(files, [file] if file == Path::new("-"))
+}
+
+/// Read source code from stdin and check it for issues, treating it as if it
+/// came from `path`.
+#[allow(clippy::too_many_arguments)]
+fn check_stdin(
+    path: &Path,
+    rules: &RuleTable,
+    path_rules: &Vec<PathRuleEnum>,
+    text_rules: &Vec<TextRuleEnum>,
+    ast_entrypoints: &BTreeMap<&str, Vec<AstRuleEnum>>,
+    settings: &Settings,
+    unsafe_fixes: UnsafeFixes,
+    per_file_ignores: &CompiledPerFileIgnoreList,
+) -> Result<Diagnostics> {
+    let mut source = String::new();
+    io::stdin()
+        .read_to_string(&mut source)
+        .context("Failed to read from stdin")?;
+
+    let filename = path.to_string_lossy();
+    let file = SourceFileBuilder::new(filename.as_ref(), source.as_str()).finish();
+
+    check_file(
+        rules,
+        path_rules,
+        text_rules,
+        ast_entrypoints,
+        path,
+        &file,
+        settings,
+        FixMode::Generate,
+        unsafe_fixes,
+        per_file_ignores,
+    )
+}
+
+/// Work out which progress bar to actually display. Progress bars are only
+/// drawn alongside human-readable output, as they would otherwise get mixed
+/// in with machine-readable formats, and fancy progress bars fall back to
+/// ascii when colours aren't supported.
+fn resolve_progress_bar(
+    progress_bar: ProgressBar,
+    output_format: OutputFormat,
+    colorize: bool,
+) -> ProgressBar {
+    if !matches!(output_format, OutputFormat::Full | OutputFormat::Concise) {
+        return ProgressBar::Off;
+    }
+    if progress_bar == ProgressBar::Fancy && !colorize {
+        return ProgressBar::Ascii;
+    }
+    progress_bar
+}
+
 // Taken from Ruff
 #[derive(Clone, Debug, Default)]
 pub struct RuleSelection {
@@ -900,7 +1130,56 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
         .unwrap_or(file_settings.file_extensions);
 
     let settings = Settings {
+        ignore_allow_comments: resolve_bool_arg(
+            args.ignore_allow_comments,
+            args.no_ignore_allow_comments,
+        )
+        .unwrap_or(file_settings.ignore_allow_comments),
         line_length: args.line_length.unwrap_or(file_settings.line_length),
+        operator_spacing: args
+            .operator_spacing
+            .unwrap_or(file_settings.operator_spacing),
+        require_real_leading_zero: resolve_bool_arg(
+            args.require_real_leading_zero,
+            args.no_require_real_leading_zero,
+        )
+        .unwrap_or(file_settings.require_real_leading_zero),
+        allowed_volatile_names: NamePatternSet::try_from_iter(
+            args.allowed_volatile_names
+                .unwrap_or(file_settings.allowed_volatile_names),
+        )?,
+        target_std: args.target_std.unwrap_or(file_settings.target_std),
+        strict_continuation_alignment: resolve_bool_arg(
+            args.strict_continuation_alignment,
+            args.no_strict_continuation_alignment,
+        )
+        .unwrap_or(file_settings.strict_continuation_alignment),
+        coarray_support: resolve_bool_arg(args.coarray_support, args.no_coarray_support)
+            .unwrap_or(file_settings.coarray_support),
+        min_comment_spaces: args
+            .min_comment_spaces
+            .unwrap_or(file_settings.min_comment_spaces),
+        max_comment_spaces: args
+            .max_comment_spaces
+            .unwrap_or(file_settings.max_comment_spaces),
+        prefer_mpi_f08: resolve_bool_arg(args.prefer_mpi_f08, args.no_prefer_mpi_f08)
+            .unwrap_or(file_settings.prefer_mpi_f08),
+        max_internal_procedures: args
+            .max_internal_procedures
+            .unwrap_or(file_settings.max_internal_procedures),
+        require_explicit_output_unit: resolve_bool_arg(
+            args.require_explicit_output_unit,
+            args.no_require_explicit_output_unit,
+        )
+        .unwrap_or(file_settings.require_explicit_output_unit),
+        todo_markers: args.todo_markers.unwrap_or(file_settings.todo_markers),
+        max_do_single_line_length: args
+            .max_do_single_line_length
+            .unwrap_or(file_settings.max_do_single_line_length),
+        allowed_logging_modules: NamePatternSet::try_from_iter(
+            args.allowed_logging_modules
+                .unwrap_or(file_settings.allowed_logging_modules),
+        )?,
     };
 
     let rule_selection = RuleSelection {
@@ -944,15 +1223,17 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
         .unwrap_or(file_settings.gitignore_mode);
 
     let output_format = args.output_format.unwrap_or(file_settings.output_format);
+    let output_file = args.output_file.or(file_settings.output_file);
+    let summary = args.summary.or(file_settings.summary);
     let preview_mode = resolve_bool_arg(args.preview, args.no_preview)
         .map(PreviewMode::from)
         .unwrap_or(file_settings.preview);
 
-    let mut progress_bar = args.progress_bar.unwrap_or(file_settings.progress_bar);
-    // Override progress bar settings if not using colour terminal
-    if progress_bar == ProgressBar::Fancy && !colored::control::SHOULD_COLORIZE.should_colorize() {
-        progress_bar = ProgressBar::Ascii;
-    }
+    let progress_bar = resolve_progress_bar(
+        args.progress_bar.unwrap_or(file_settings.progress_bar),
+        output_format,
+        colored::control::SHOULD_COLORIZE.should_colorize(),
+    );
 
     let fix = resolve_bool_arg(args.fix, args.no_fix).unwrap_or(file_settings.fix);
     let fix_only =
@@ -987,6 +1268,62 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
     let text_rules = rules_to_text_rules(&rules);
     let ast_entrypoints = ast_entrypoint_map(&rules);
 
+    let mut printer_flags = PrinterFlags::empty();
+    if !fix_only {
+        printer_flags |= PrinterFlags::SHOW_VIOLATIONS;
+    }
+    if show_fixes {
+        printer_flags |= PrinterFlags::SHOW_FIX_SUMMARY;
+    }
+
+    let printer = Printer::new(
+        output_format,
+        global_options.log_level(),
+        printer_flags,
+        fix_mode,
+        unsafe_fixes,
+        output_file,
+        summary,
+    );
+
+    if is_stdin(files, args.stdin_filename.as_deref()) {
+        if fix_mode.is_apply() {
+            return Err(anyhow!(
+                "Applying fixes is not supported when reading from stdin"
+            ));
+        }
+
+        let path = args
+            .stdin_filename
+            .map(fs::normalize_path)
+            .unwrap_or_else(|| PathBuf::from("-"));
+
+        let diagnostics =
+            if matches!(exclude_mode, ExcludeMode::Force) && file_excludes.matches(&path) {
+                Diagnostics::default()
+            } else {
+                check_stdin(
+                    &path,
+                    &rules,
+                    &path_rules,
+                    &text_rules,
+                    &ast_entrypoints,
+                    &settings,
+                    unsafe_fixes,
+                    &per_file_ignores,
+                )?
+            };
+
+        let mut writer = Box::new(io::stdout());
+        printer.write_once(&[path], &diagnostics, &mut writer)?;
+
+        return if diagnostics.messages.is_empty() {
+            Ok(ExitCode::SUCCESS)
+        } else {
+            Ok(ExitCode::FAILURE)
+        };
+    }
+
     let start = Instant::now();
     let files = get_files(
         files,
@@ -1106,22 +1443,7 @@ pub fn check(args: CheckArgs, global_options: &GlobalConfigArgs) -> Result<ExitC
 
     let mut writer = Box::new(io::stdout());
 
-    let mut printer_flags = PrinterFlags::empty();
-    if !fix_only {
-        printer_flags |= PrinterFlags::SHOW_VIOLATIONS;
-    }
-    if show_fixes {
-        printer_flags |= PrinterFlags::SHOW_FIX_SUMMARY;
-    }
-
-    Printer::new(
-        output_format,
-        global_options.log_level(),
-        printer_flags,
-        fix_mode,
-        unsafe_fixes,
-    )
-    .write_once(files.len(), &all_diagnostics, &mut writer)?;
+    printer.write_once(&files, &all_diagnostics, &mut writer)?;
 
     if total_errors == 0 {
         Ok(ExitCode::SUCCESS)
@@ -1290,4 +1612,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn find_and_check_pyproject_toml() -> Result<()> {
+        let tempdir = TempDir::new()?;
+        let pyproject_toml = tempdir.path().join("pyproject.toml");
+        fs::write(
+            pyproject_toml,
+            dedent(
+                r#"
+                [project]
+                name = "some-project"
+
+                [tool.ruff]
+                line-length = 88
+
+                [tool.fortitude.check]
+                select = ["C541"]
+                "#,
+            ),
+        )?;
+
+        let pyproject =
+            find_settings_toml(tempdir.path())?.context("Failed to find pyproject.toml")?;
+        assert!(pyproject_fortitude_enabled(&pyproject)?);
+
+        let settings = parse_config_file(&Some(pyproject))?;
+        assert_eq!(settings.select, Some(vec![RuleSelector::from_str("C541")?]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn pyproject_toml_without_fortitude_section() -> Result<()> {
+        let tempdir = TempDir::new()?;
+        let pyproject_toml = tempdir.path().join("pyproject.toml");
+        fs::write(
+            pyproject_toml,
+            dedent(
+                r#"
+                [tool.ruff]
+                line-length = 88
+                "#,
+            ),
+        )?;
+
+        assert_eq!(settings_toml(tempdir.path())?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress_bar_only_with_text_output() {
+        for output_format in [OutputFormat::Full, OutputFormat::Concise] {
+            assert_eq!(
+                resolve_progress_bar(ProgressBar::Fancy, output_format, true),
+                ProgressBar::Fancy
+            );
+            assert_eq!(
+                resolve_progress_bar(ProgressBar::Ascii, output_format, true),
+                ProgressBar::Ascii
+            );
+        }
+
+        for output_format in [
+            OutputFormat::Json,
+            OutputFormat::Sarif,
+            OutputFormat::Github,
+        ] {
+            assert_eq!(
+                resolve_progress_bar(ProgressBar::Fancy, output_format, true),
+                ProgressBar::Off
+            );
+            assert_eq!(
+                resolve_progress_bar(ProgressBar::Ascii, output_format, true),
+                ProgressBar::Off
+            );
+        }
+    }
+
+    #[test]
+    fn progress_bar_fancy_without_colour() {
+        assert_eq!(
+            resolve_progress_bar(ProgressBar::Fancy, OutputFormat::Full, false),
+            ProgressBar::Ascii
+        );
+        assert_eq!(
+            resolve_progress_bar(ProgressBar::Off, OutputFormat::Full, false),
+            ProgressBar::Off
+        );
+    }
 }
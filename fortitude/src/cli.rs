@@ -6,7 +6,10 @@ use crate::{
     build,
     logging::LogLevel,
     rule_selector::RuleSelector,
-    settings::{FilePattern, OutputFormat, PatternPrefixPair, ProgressBar},
+    settings::{
+        FilePattern, FortranStandard, MaxCommentSpaces, OperatorSpacing, OutputFormat,
+        PatternPrefixPair, ProgressBar, SummaryMode,
+    },
     RuleSelectorParser,
 };
 
@@ -111,7 +114,7 @@ pub struct ExplainArgs {
 pub struct CheckArgs {
     /// List of files or directories to check. Directories are searched recursively for
     /// Fortran files. The `--file-extensions` option can be used to control which files
-    /// are included in the search.
+    /// are included in the search. Pass `-` to read from stdin.
     #[arg(default_value = ".")]
     pub files: Option<Vec<PathBuf>>,
 
@@ -148,6 +151,22 @@ pub struct CheckArgs {
     #[arg(long, value_enum, env = "FORTITUDE_OUTPUT_FORMAT")]
     pub output_format: Option<OutputFormat>,
 
+    /// Write diagnostics to this file instead of stdout. The summary of the number of
+    /// errors is still printed to stdout.
+    #[arg(long)]
+    pub output_file: Option<PathBuf>,
+
+    /// Only print the number of violations in each file, instead of every violation.
+    /// Files without violations are omitted, unless `--summary=all` is used.
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "violations"
+    )]
+    pub summary: Option<SummaryMode>,
+
     /// Enable preview mode; checks will include unstable rules and fixes.
     /// Use `--no-preview` to disable.
     #[arg(long, overrides_with("no_preview"), action = SetTrue)]
@@ -155,8 +174,15 @@ pub struct CheckArgs {
     #[clap(long, overrides_with("preview"), hide = true, action = SetTrue)]
     pub no_preview: Option<bool>,
 
+    /// The Fortran standard that code should conform to. Rules about portability
+    /// report features introduced after this standard.
+    /// Options are "f77", "f90", "f95", "f2003", "f2008", "f2018", and "f2023" (default).
+    #[arg(long, value_enum)]
+    pub target_std: Option<FortranStandard>,
+
     /// Progress bar settings.
-    /// Options are "off" (default), "ascii", and "fancy"
+    /// Options are "off" (default), "ascii", and "fancy".
+    /// Only shown on a terminal with the "full" or "concise" output formats
     #[arg(long, value_enum)]
     pub progress_bar: Option<ProgressBar>,
 
@@ -212,6 +238,13 @@ pub struct CheckArgs {
     )]
     pub extend_per_file_ignores: Option<Vec<PatternPrefixPair>>,
 
+    /// Ignore any `allow` and `noqa` comments, reporting every violation.
+    /// Use `--no-ignore-allow-comments` to disable.
+    #[arg(long, overrides_with("no_ignore_allow_comments"), help_heading = "Rule selection", action = SetTrue)]
+    pub ignore_allow_comments: Option<bool>,
+    #[clap(long, overrides_with("ignore_allow_comments"), hide = true, action = SetTrue)]
+    pub no_ignore_allow_comments: Option<bool>,
+
     // File selection
     /// File extensions to check
     #[arg(
@@ -254,8 +287,100 @@ pub struct CheckArgs {
     #[clap(long, overrides_with("respect_gitignore"), hide = true, action = SetTrue)]
     pub no_respect_gitignore: Option<bool>,
 
+    /// The name of the file when passing it through stdin. Used for
+    /// per-file-ignores, exclusions, and reporting diagnostics.
+    #[arg(long, help_heading = "File selection")]
+    #[serde(skip)]
+    pub stdin_filename: Option<PathBuf>,
+
     // Options for individual rules
     /// Set the maximum allowable line length.
     #[arg(long, help_heading = "Per-Rule Options")]
     pub line_length: Option<usize>,
+
+    /// Set whether binary operators should always or never be surrounded by spaces.
+    /// Options are "always" (default) and "never".
+    #[arg(long, value_enum, help_heading = "Per-Rule Options")]
+    pub operator_spacing: Option<OperatorSpacing>,
+
+    /// Also require real literals to have a digit before the decimal point, as in `0.5`
+    /// rather than `.5`. Use `--no-require-real-leading-zero` to disable.
+    #[arg(long, overrides_with("no_require_real_leading_zero"), help_heading = "Per-Rule Options", action = SetTrue)]
+    pub require_real_leading_zero: Option<bool>,
+    #[clap(long, overrides_with("require_real_leading_zero"), hide = true, action = SetTrue)]
+    pub no_require_real_leading_zero: Option<bool>,
+
+    /// Comma-separated list of glob patterns for variable names that may be declared
+    /// `volatile` without justification.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Per-Rule Options"
+    )]
+    pub allowed_volatile_names: Option<Vec<String>>,
+
+    /// Require continuation lines inside brackets to line up exactly with the opening
+    /// bracket. Use `--no-strict-continuation-alignment` to disable.
+    #[arg(long, overrides_with("no_strict_continuation_alignment"), help_heading = "Per-Rule Options", action = SetTrue)]
+    pub strict_continuation_alignment: Option<bool>,
+    #[clap(long, overrides_with("strict_continuation_alignment"), hide = true, action = SetTrue)]
+    pub no_strict_continuation_alignment: Option<bool>,
+
+    /// Declare that the project is built with a compiler that supports coarrays,
+    /// so coarray declarations are not reported. Use `--no-coarray-support` to disable.
+    #[arg(long, overrides_with("no_coarray_support"), help_heading = "Per-Rule Options", action = SetTrue)]
+    pub coarray_support: Option<bool>,
+    #[clap(long, overrides_with("coarray_support"), hide = true, action = SetTrue)]
+    pub no_coarray_support: Option<bool>,
+
+    /// Set the minimum number of spaces required before an inline comment.
+    #[arg(long, help_heading = "Per-Rule Options")]
+    pub min_comment_spaces: Option<usize>,
+
+    /// Set the maximum number of spaces allowed before an inline comment, or
+    /// "unlimited" to only check the minimum.
+    #[arg(long, help_heading = "Per-Rule Options")]
+    pub max_comment_spaces: Option<MaxCommentSpaces>,
+
+    /// Report `use mpi` when targeting Fortran 2008 or later, so that the `mpi_f08`
+    /// module is used instead. Use `--no-prefer-mpi-f08` to disable.
+    #[arg(long, overrides_with("no_prefer_mpi_f08"), help_heading = "Per-Rule Options", action = SetTrue)]
+    pub prefer_mpi_f08: Option<bool>,
+    #[clap(long, overrides_with("prefer_mpi_f08"), hide = true, action = SetTrue)]
+    pub no_prefer_mpi_f08: Option<bool>,
+
+    /// Set the maximum number of internal procedures allowed in a subroutine or function.
+    #[arg(long, help_heading = "Per-Rule Options")]
+    pub max_internal_procedures: Option<usize>,
+
+    /// Require `write` statements to name their unit, such as `output_unit`, instead
+    /// of using `*`. Use `--no-require-explicit-output-unit` to disable.
+    #[arg(long, overrides_with("no_require_explicit_output_unit"), help_heading = "Per-Rule Options", action = SetTrue)]
+    pub require_explicit_output_unit: Option<bool>,
+    #[clap(long, overrides_with("require_explicit_output_unit"), hide = true, action = SetTrue)]
+    pub no_require_explicit_output_unit: Option<bool>,
+
+    /// Comma-separated list of comment markers, such as "TODO" or "FIXME", to report.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "MARKER",
+        help_heading = "Per-Rule Options"
+    )]
+    pub todo_markers: Option<Vec<String>>,
+
+    /// Set the maximum length of a single-statement `do` loop written on one line.
+    #[arg(long, help_heading = "Per-Rule Options")]
+    pub max_do_single_line_length: Option<usize>,
+
+    /// Comma-separated list of glob patterns for module names that may write to
+    /// standard error, such as logging modules.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Per-Rule Options"
+    )]
+    pub allowed_logging_modules: Option<Vec<String>>,
 }
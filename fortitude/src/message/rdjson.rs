@@ -28,10 +28,7 @@ impl Emitter for RdjsonEmitter {
         serde_json::to_writer_pretty(
             writer,
             &json!({
-                "source": {
-                    "name": "fortitude",
-                    "url": "https://github.com/PlasmaFAIR/fortitude",
-                },
+                "source": rdjson_source(),
                 "severity": "warning",
                 "diagnostics": &ExpandedMessages{ messages }
             }),
@@ -78,6 +75,7 @@ fn message_to_rdjson_value(message: &DiagnosticMessage) -> Value {
                 "value": message.rule().map(|rule| rule.noqa_code().to_string()),
                 // "url": message.rule().and_then(|rule| rule.url()),
             },
+            "source": rdjson_source(),
             "suggestions": rdjson_suggestions(fix.edits(), &source_code),
         })
     } else {
@@ -91,10 +89,20 @@ fn message_to_rdjson_value(message: &DiagnosticMessage) -> Value {
                 "value": message.rule().map(|rule| rule.noqa_code().to_string()),
                 // "url": message.rule().and_then(|rule| rule.url()),
             },
+            "source": rdjson_source(),
         })
     }
 }
 
+/// The tool that produced the diagnostics, given both for the whole result and
+/// for each diagnostic
+fn rdjson_source() -> Value {
+    json!({
+        "name": "fortitude",
+        "url": "https://github.com/PlasmaFAIR/fortitude",
+    })
+}
+
 fn rdjson_suggestions(edits: &[Edit], source_code: &SourceCode) -> Value {
     Value::Array(
         edits
@@ -1,7 +1,10 @@
 use std::cmp::Reverse;
-use std::io::Write;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use bitflags::bitflags;
 use colored::Colorize;
 use itertools::{iterate, Itertools};
@@ -13,7 +16,7 @@ use crate::message::{
     AzureEmitter, Emitter, GithubEmitter, GitlabEmitter, GroupedEmitter, JsonEmitter,
     JsonLinesEmitter, JunitEmitter, PylintEmitter, RdjsonEmitter, SarifEmitter, TextEmitter,
 };
-use crate::settings::{FixMode, OutputFormat, UnsafeFixes};
+use crate::settings::{FixMode, OutputFormat, SummaryMode, UnsafeFixes};
 
 bitflags! {
     #[derive(Default, Debug, Copy, Clone)]
@@ -33,6 +36,8 @@ pub(crate) struct Printer {
     flags: Flags,
     fix_mode: FixMode,
     unsafe_fixes: UnsafeFixes,
+    output_path: Option<PathBuf>,
+    summary: Option<SummaryMode>,
 }
 
 impl Printer {
@@ -42,6 +47,8 @@ impl Printer {
         flags: Flags,
         fix_mode: FixMode,
         unsafe_fixes: UnsafeFixes,
+        output_path: Option<PathBuf>,
+        summary: Option<SummaryMode>,
     ) -> Self {
         Self {
             format,
@@ -49,6 +56,8 @@ impl Printer {
             flags,
             fix_mode,
             unsafe_fixes,
+            output_path,
+            summary,
         }
     }
 
@@ -162,13 +171,57 @@ impl Printer {
 
     pub(crate) fn write_once(
         &self,
-        num_files: usize,
+        files: &[PathBuf],
         diagnostics: &Diagnostics,
         writer: &mut dyn Write,
     ) -> Result<()> {
         if matches!(self.log_level, LogLevel::Silent) {
             return Ok(());
         }
+        let num_files = files.len();
+
+        // When writing to a file, only the summary goes to `writer`
+        if let Some(output_path) = &self.output_path {
+            let file = File::create(output_path).with_context(|| {
+                format!("Failed to create output file {}", output_path.display())
+            })?;
+            let mut file_writer = BufWriter::new(file);
+
+            // Don't write colour codes into the file, but keep them for the
+            // summary written to `writer`
+            let colorize = colored::control::SHOULD_COLORIZE.should_colorize();
+            colored::control::set_override(false);
+            let result = self.write_diagnostics(files, diagnostics, &mut file_writer, false);
+            colored::control::set_override(colorize);
+            result?;
+            file_writer.flush()?;
+
+            self.write_summary_text(writer, diagnostics, num_files)?;
+            writer.flush()?;
+            return Ok(());
+        }
+
+        self.write_diagnostics(files, diagnostics, writer, true)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn write_diagnostics(
+        &self,
+        files: &[PathBuf],
+        diagnostics: &Diagnostics,
+        writer: &mut dyn Write,
+        show_summary: bool,
+    ) -> Result<()> {
+        let num_files = files.len();
+
+        if let Some(summary) = self.summary {
+            write_file_summary(writer, files, diagnostics, summary)?;
+            if show_summary {
+                self.write_summary_text(writer, diagnostics, num_files)?;
+            }
+            return Ok(());
+        }
 
         let fixables = FixableStatistics::try_from(diagnostics, self.unsafe_fixes);
 
@@ -187,7 +240,9 @@ impl Printer {
                     writeln!(writer)?;
                 }
 
-                self.write_summary_text(writer, diagnostics, num_files)?;
+                if show_summary {
+                    self.write_summary_text(writer, diagnostics, num_files)?;
+                }
             }
             OutputFormat::Github => {
                 GithubEmitter.emit(writer, &diagnostics.messages)?;
@@ -206,7 +261,9 @@ impl Printer {
                     print_fix_summary(writer, &diagnostics.fixed)?;
                     writeln!(writer)?;
                 }
-                self.write_summary_text(writer, diagnostics, num_files)?;
+                if show_summary {
+                    self.write_summary_text(writer, diagnostics, num_files)?;
+                }
             }
             OutputFormat::Json => {
                 JsonEmitter.emit(writer, &diagnostics.messages)?;
@@ -231,7 +288,6 @@ impl Printer {
             }
         }
 
-        writer.flush()?;
         Ok(())
     }
 }
@@ -253,6 +309,29 @@ fn show_fix_status(fix_mode: FixMode, fixables: Option<&FixableStatistics>) -> b
     (!fix_mode.is_apply()) && fixables.is_some_and(FixableStatistics::any_applicable_fixes)
 }
 
+/// Write the number of violations in each file, one file per line
+fn write_file_summary(
+    writer: &mut dyn Write,
+    files: &[PathBuf],
+    diagnostics: &Diagnostics,
+    summary: SummaryMode,
+) -> Result<()> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    if summary == SummaryMode::All {
+        for file in files {
+            counts.insert(file.to_string_lossy().into_owned(), 0);
+        }
+    }
+    for message in &diagnostics.messages {
+        *counts.entry(message.filename().to_string()).or_default() += 1;
+    }
+
+    for (filename, count) in counts {
+        writeln!(writer, "{}: {count}", relativize_path(filename).bold())?;
+    }
+    Ok(())
+}
+
 fn print_fix_summary(writer: &mut dyn Write, fixed: &FixMap) -> Result<()> {
     let total = fixed
         .values()
@@ -80,6 +80,15 @@ pub enum Category {
     /// Issues that are susceptible to bugs.
     #[prefix = "B"]
     Bugprone,
+    /// Code that is likely to be incorrect or has undefined behaviour.
+    #[prefix = "C"]
+    Correctness,
+    /// Issues with Fortitude itself, such as its suppression comments.
+    #[prefix = "FORT"]
+    Fortitude,
+    /// Features that may not be available with every compiler or standard.
+    #[prefix = "PORT"]
+    Portability,
 }
 
 pub trait RuleNamespace: Sized {
@@ -121,12 +121,55 @@ pub(crate) fn is_single_rule_selector(prefix: &RuleCodePrefix) -> bool {
 
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
-    #[error("Unknown rule selector: `{0}`")]
+    #[error("Unknown rule selector: `{0}`{}", did_you_mean(.0))]
     // TODO(martin): tell the user how to discover rule codes via the CLI once such a command is
     // implemented (but that should of course be done only in ruff and not here)
     Unknown(String),
 }
 
+/// Maximum edit distance for suggesting a rule or category name
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// A suggestion for a misspelled selector, such as "Did you mean
+/// `implicit-typing`?", or an empty string if nothing is close enough
+fn did_you_mean(selector: &str) -> String {
+    match closest_selector(selector) {
+        Some(suggestion) => format!(". Did you mean `{suggestion}`?"),
+        None => String::new(),
+    }
+}
+
+/// The rule name, rule code, or category name closest to `selector`, if any
+/// are within [`MAX_SUGGESTION_DISTANCE`] edits. Short selectors need a closer
+/// match, so that we don't suggest unrelated codes.
+fn closest_selector(selector: &str) -> Option<String> {
+    let selector = selector.to_lowercase();
+    let max_distance = MAX_SUGGESTION_DISTANCE.min(selector.chars().count().saturating_sub(1) / 2);
+    Rule::iter()
+        .flat_map(|rule| [rule.as_ref().to_string(), rule.noqa_code().to_string()])
+        .chain(Category::iter().map(|category| category.as_ref().to_string()))
+        .map(|candidate| (levenshtein(&selector, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Number of single character insertions, deletions, or substitutions needed
+/// to turn `a` into `b`
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 impl RuleSelector {
     pub fn prefix_and_code(&self) -> (&'static str, &'static str) {
         match self {
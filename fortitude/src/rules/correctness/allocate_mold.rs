@@ -0,0 +1,93 @@
+use crate::ast::{declaration_type, find_variable_declaration, is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `allocate` statements with a `mold=` argument whose declared
+/// type differs from the type of the object being allocated.
+///
+/// ## Why is this bad?
+/// `mold=` gives the allocated object the type and shape of another
+/// expression, so the two must have compatible types. A mismatch is an error,
+/// and is often the result of copying an `allocate` statement and not
+/// updating the mold.
+///
+/// This rule only compares variables with intrinsic types that are declared
+/// in the current or an enclosing scope.
+///
+/// ## Example
+/// ```f90
+/// real, allocatable :: x(:)
+/// integer :: y(10)
+/// allocate (x, mold=y)
+/// ```
+#[violation]
+pub struct MismatchedAllocateMold {
+    name: String,
+    type_: String,
+    mold: String,
+    mold_type: String,
+}
+
+impl Violation for MismatchedAllocateMold {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            name,
+            type_,
+            mold,
+            mold_type,
+        } = self;
+        format!("'{type_}' variable '{name}' allocated with mold '{mold}' of type '{mold_type}'")
+    }
+}
+
+impl AstRule for MismatchedAllocateMold {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let mold = node
+            .named_children(&mut node.walk())
+            .find(|child| is_keyword_argument(child, "mold", text))?
+            .child_by_field_name("value")?;
+        if mold.kind() != "identifier" {
+            return None;
+        }
+        let mold_name = mold.to_text(text)?;
+        let mold_type = declaration_type(&find_variable_declaration(node, mold_name, text)?, text)?;
+
+        let violations = node
+            .named_children(&mut node.walk())
+            .filter(|child| !matches!(child.kind(), "keyword_argument" | "comment"))
+            .filter_map(|object| {
+                // Find the variable at the root of objects like `x(n)` or `a%x`
+                let mut object = object;
+                while object.kind() != "identifier" {
+                    object = object.named_child(0)?;
+                }
+                let name = object.to_text(text)?;
+                let type_ = declaration_type(&find_variable_declaration(node, name, text)?, text)?;
+                if type_ == mold_type {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                        type_,
+                        mold: mold_name.to_string(),
+                        mold_type: mold_type.clone(),
+                    },
+                    &mold,
+                ))
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["allocate_statement"]
+    }
+}
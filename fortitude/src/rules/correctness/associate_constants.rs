@@ -0,0 +1,169 @@
+use crate::ast::{find_variable_declaration, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `associate` constructs where the selector is a literal constant
+/// or a `parameter`.
+///
+/// ## Why is this bad?
+/// `associate` is useful for giving a short name to a complicated expression
+/// or a deeply nested component. Associating a name with a constant only adds
+/// an extra name for the reader to keep track of: a literal can be written
+/// directly, and a `parameter` already has a name.
+///
+/// The fix replaces each use of the associate name with the constant and
+/// removes the `associate` construct. It is only available for constructs
+/// with a single association, and is unsafe, as the body is not re-indented
+/// and any reuse of the name in nested scopes is not accounted for.
+///
+/// ## Example
+/// ```f90
+/// associate (pi => 3.14159_real64)
+///   area = pi * radius**2
+/// end associate
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// area = 3.14159_real64 * radius**2
+/// ```
+#[violation]
+pub struct ConstantAssociateSelector {
+    name: String,
+    selector: String,
+}
+
+impl Violation for ConstantAssociateSelector {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, selector } = self;
+        format!("associate name '{name}' refers to constant '{selector}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { selector, .. } = self;
+        Some(format!("Use '{selector}' directly"))
+    }
+}
+
+impl AstRule for ConstantAssociateSelector {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name = node.named_child(0)?.to_text(text)?;
+        let selector = node.named_child(node.named_child_count() - 1)?;
+        if !is_constant(&selector, text) {
+            return None;
+        }
+
+        let selector_text = selector.to_text(text)?;
+        let diagnostic = Diagnostic::from_node(
+            Self {
+                name: name.to_string(),
+                selector: selector_text.to_string(),
+            },
+            node,
+        );
+        match inline_associate(node, name, selector_text, src) {
+            Some(fix) => some_vec!(diagnostic.with_fix(fix)),
+            None => some_vec!(diagnostic),
+        }
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["association"]
+    }
+}
+
+fn is_constant(selector: &Node, src: &str) -> bool {
+    match selector.kind() {
+        "number_literal" | "string_literal" | "boolean_literal" | "complex_literal" => true,
+        "unary_expression" | "parenthesized_expression" => selector
+            .named_child(selector.named_child_count().saturating_sub(1))
+            .is_some_and(|operand| is_constant(&operand, src)),
+        "identifier" => selector
+            .to_text(src)
+            .and_then(|name| find_variable_declaration(selector, name, src))
+            .is_some_and(|declaration| {
+                declaration
+                    .children_by_field_name("attribute", &mut declaration.walk())
+                    .filter_map(|attribute| attribute.to_text(src))
+                    .any(|attribute| attribute.eq_ignore_ascii_case("parameter"))
+            }),
+        _ => false,
+    }
+}
+
+/// Replace uses of `name` in the `associate` block with `selector`, and remove
+/// the `associate` and `end associate` statements
+fn inline_associate(
+    association: &Node,
+    name: &str,
+    selector: &str,
+    src: &SourceFile,
+) -> Option<Fix> {
+    let text = src.source_text();
+    let statement = association.parent()?;
+    if statement.kind() != "associate_statement" {
+        return None;
+    }
+    let num_associations = statement
+        .named_children(&mut statement.walk())
+        .filter(|child| child.kind() == "association")
+        .count();
+    if num_associations != 1 {
+        return None;
+    }
+    let block = statement.parent()?;
+    let end_statement = block.named_child(block.named_child_count().checked_sub(1)?)?;
+    if end_statement.kind() != "end_associate_statement" {
+        return None;
+    }
+
+    let replacement = if association
+        .named_child(association.named_child_count() - 1)?
+        .kind()
+        == "unary_expression"
+    {
+        format!("({selector})")
+    } else {
+        selector.to_string()
+    };
+
+    let uses = block
+        .named_descendants()
+        .filter(|child| child.kind() == "identifier")
+        .filter(|child| {
+            child.start_byte() >= statement.end_byte()
+                && child.end_byte() <= end_statement.start_byte()
+        })
+        .filter(|child| {
+            child
+                .to_text(text)
+                .is_some_and(|id| id.eq_ignore_ascii_case(name))
+        })
+        .filter(|child| !is_component_or_keyword(child))
+        .map(|child| child.edit_replacement(src, replacement.clone()));
+
+    let edits: Vec<Edit> = uses.chain([end_statement.edit_delete(src)]).collect();
+    Some(Fix::unsafe_edits(statement.edit_delete(src), edits))
+}
+
+/// True if `identifier` is the name of a derived type component, as in `a%name`,
+/// or the keyword of a keyword argument
+fn is_component_or_keyword(identifier: &Node) -> bool {
+    let Some(parent) = identifier.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        "derived_type_member_expression" => parent.named_child(0) != Some(*identifier),
+        "keyword_argument" => parent.child_by_field_name("name") == Some(*identifier),
+        _ => false,
+    }
+}
@@ -0,0 +1,87 @@
+use crate::ast::{scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `associate` names that are the same as a name imported with
+/// `use, only:` in the current or an enclosing scope.
+///
+/// ## Why is this bad?
+/// Inside the `associate` block, the associate name hides the imported
+/// entity, so code that looks like it refers to a module variable or
+/// procedure is actually using the associated expression. Pick a different
+/// name to keep the two apart.
+///
+/// Only names listed explicitly in `only` lists are checked.
+///
+/// ## Example
+/// ```f90
+/// use physics, only: energy
+/// ...
+/// associate (energy => state%kinetic + state%potential)
+///   ...
+/// end associate
+/// ```
+#[violation]
+pub struct AssociateShadowsUseName {
+    name: String,
+    module: String,
+}
+
+impl Violation for AssociateShadowsUseName {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, module } = self;
+        format!("associate name '{name}' shadows '{name}' from module '{module}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Rename the associate name".to_string())
+    }
+}
+
+impl AstRule for AssociateShadowsUseName {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name_node = node.named_child(0)?;
+        let name = name_node.to_text(text)?;
+
+        let mut scope = scoping_unit(node);
+        while let Some(unit) = scope {
+            let module = unit
+                .named_children(&mut unit.walk())
+                .filter(|child| child.kind() == "use_statement")
+                .filter(|statement| {
+                    statement
+                        .child_with_name("included_items")
+                        .is_some_and(|items| {
+                            items
+                                .named_descendants()
+                                .filter(|item| item.kind() == "identifier")
+                                .filter_map(|item| item.to_text(text))
+                                .any(|item| item.eq_ignore_ascii_case(name))
+                        })
+                })
+                .find_map(|statement| statement.child_with_name("module_name")?.to_text(text));
+            if let Some(module) = module {
+                return some_vec!(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                        module: module.to_string(),
+                    },
+                    &name_node
+                ));
+            }
+            scope = scoping_unit(&unit);
+        }
+        None
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["association"]
+    }
+}
@@ -0,0 +1,104 @@
+use crate::ast::{find_variable_declaration, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for calls to `c_f_pointer` with a `type(c_ptr)` variable that
+/// hasn't been checked with `c_associated` first.
+///
+/// ## Why is this bad?
+/// Pointers coming from C may be null. Converting a null `c_ptr` with
+/// `c_f_pointer` gives a Fortran pointer that can't be safely used, and
+/// dereferencing it will typically crash the program. Check the pointer with
+/// `c_associated` beforehand, either in an enclosing `if` or in an earlier
+/// statement of the same procedure.
+///
+/// ## Example
+/// ```f90
+/// call c_f_pointer(ptr, array, [n])
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// if (c_associated(ptr)) then
+///   call c_f_pointer(ptr, array, [n])
+/// end if
+/// ```
+#[violation]
+pub struct UncheckedCPointer {
+    name: String,
+}
+
+impl Violation for UncheckedCPointer {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'{name}' passed to 'c_f_pointer' without checking 'c_associated({name})'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Check the pointer with 'c_associated' first".to_string())
+    }
+}
+
+impl AstRule for UncheckedCPointer {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if !node
+            .named_child(0)?
+            .to_text(text)?
+            .eq_ignore_ascii_case("c_f_pointer")
+        {
+            return None;
+        }
+
+        let pointer = node.child_with_name("argument_list")?.named_child(0)?;
+        if pointer.kind() != "identifier" {
+            return None;
+        }
+        let name = pointer.to_text(text)?;
+        let declaration = find_variable_declaration(node, name, text)?;
+        let type_ = declaration.child_by_field_name("type")?.to_text(text)?;
+        let type_: String = type_.split_whitespace().collect();
+        if !type_.eq_ignore_ascii_case("type(c_ptr)") {
+            return None;
+        }
+
+        // Look for `c_associated(name)` anywhere before this call
+        let scope = scoping_unit(node)?;
+        let is_checked = scope
+            .named_descendants()
+            .filter(|child| child.kind() == "call_expression")
+            .filter(|call| call.start_byte() < node.start_byte())
+            .filter(|call| {
+                call.named_child(0)
+                    .and_then(|callee| callee.to_text(text))
+                    .is_some_and(|callee| callee.eq_ignore_ascii_case("c_associated"))
+            })
+            .filter_map(|call| call.child_with_name("argument_list"))
+            .any(|arguments| {
+                arguments
+                    .named_children(&mut arguments.walk())
+                    .filter_map(|argument| argument.to_text(text))
+                    .any(|argument| argument.eq_ignore_ascii_case(name))
+            });
+        if is_checked {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string()
+            },
+            &pointer
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine_call"]
+    }
+}
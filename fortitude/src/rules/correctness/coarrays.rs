@@ -0,0 +1,89 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for coarray declarations, either with a codimension in square
+/// brackets such as `x[*]`, or with the `codimension` attribute.
+///
+/// ## Why is this bad?
+/// Coarrays require a compiler with coarray support, and often extra flags or
+/// libraries such as OpenCoarrays to build. Code that uses them can't be built
+/// with every compiler, so this rule can be selected to find where a project
+/// depends on coarray support. Projects that rely on coarrays should set
+/// `--coarray-support` to switch this rule off.
+///
+/// ## Example
+/// ```f90
+/// real :: x[*]
+/// integer, codimension[*] :: counter
+/// ```
+#[violation]
+pub struct CoarrayDeclaration {
+    name: String,
+}
+
+impl Violation for CoarrayDeclaration {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("coarray '{name}' requires a compiler with coarray support")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Set '--coarray-support' if coarrays are intended".to_string())
+    }
+}
+
+impl AstRule for CoarrayDeclaration {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.coarray_support {
+            return None;
+        }
+
+        let text = src.source_text();
+        let has_codimension = node
+            .children_by_field_name("attribute", &mut node.walk())
+            .filter_map(|attribute| attribute.to_text(text))
+            .any(|attribute| attribute.to_lowercase().starts_with("codimension"));
+
+        let violations = node
+            .children_by_field_name("declarator", &mut node.walk())
+            .filter_map(|declarator| {
+                // Ignore any initialisation, which may contain array constructors
+                let object = match declarator.kind() {
+                    "init_declarator" | "pointer_init_declarator" => {
+                        declarator.child_by_field_name("left")?
+                    }
+                    _ => declarator,
+                };
+                if !has_codimension && !object.to_text(text)?.contains('[') {
+                    return None;
+                }
+                let name = if object.kind() == "identifier" {
+                    object
+                } else {
+                    object
+                        .named_descendants()
+                        .find(|child| child.kind() == "identifier")?
+                };
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_text(text)?.to_string(),
+                    },
+                    &object,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
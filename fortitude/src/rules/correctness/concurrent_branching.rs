@@ -0,0 +1,85 @@
+use crate::ast::{FortitudeNode, SCOPING_UNITS};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `stop` and `return` statements inside `do concurrent` and
+/// `forall` constructs.
+///
+/// ## Why is this bad?
+/// The iterations of `do concurrent` and `forall` may run in any order, or at
+/// the same time, so the standard doesn't allow branching out of them part way
+/// through. `stop` and `return` are not permitted inside these constructs, and
+/// compilers may reject the code or produce unpredictable results.
+///
+/// `error stop` is allowed in `do concurrent` since Fortran 2018, and is not
+/// reported.
+///
+/// ## Example
+/// ```f90
+/// do concurrent (i = 1:n)
+///   if (x(i) < 0) stop
+///   y(i) = sqrt(x(i))
+/// end do
+/// ```
+#[violation]
+pub struct BranchInConcurrentLoop {
+    statement: String,
+    construct: String,
+}
+
+impl Violation for BranchInConcurrentLoop {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            statement,
+            construct,
+        } = self;
+        format!("'{statement}' not allowed inside '{construct}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Check the condition before the loop, or use 'error stop'".to_string())
+    }
+}
+
+impl AstRule for BranchInConcurrentLoop {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = node.to_text(text)?.to_lowercase();
+        let statement = statement.split_whitespace().next()?;
+        if !matches!(statement, "stop" | "return") {
+            return None;
+        }
+
+        let construct = node
+            .ancestors()
+            .take_while(|ancestor| !SCOPING_UNITS.contains(&ancestor.kind()))
+            .find_map(|ancestor| {
+                let first_line = ancestor.to_text(text)?.lines().next()?;
+                let (_, construct) = regex_captures!(
+                    r"^(?i)(?:\w+\s*:\s*)?(do\s+concurrent|forall)\b",
+                    first_line
+                )?;
+                Some(construct.split_whitespace().collect::<Vec<_>>().join(" "))
+            })?
+            .to_lowercase();
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                statement: statement.to_string(),
+                construct,
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["stop_statement", "keyword_statement"]
+    }
+}
@@ -0,0 +1,143 @@
+use crate::ast::{is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for variables that are deallocated twice, with no `allocate` in
+/// between.
+///
+/// ## Why is this bad?
+/// Deallocating an `allocatable` variable that is not allocated is an error,
+/// and will stop the program unless `stat=` is given. Deallocating a `pointer`
+/// that has already been deallocated is worse: the behaviour is undefined, and
+/// may corrupt memory or crash long after the offending statement.
+///
+/// This rule only looks for a second `deallocate` later in the same block as
+/// the first, and skips any variable that is used in between, in case it is
+/// reallocated or re-associated. A first `deallocate` with `stat=` is ignored,
+/// as the following code may be handling its failure.
+///
+/// ## Example
+/// ```f90
+/// deallocate(x)
+/// ...
+/// deallocate(x)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// deallocate(x)
+/// ...
+/// allocate(x(n))
+/// ...
+/// deallocate(x)
+/// ```
+#[violation]
+pub struct DoubleDeallocate {
+    name: String,
+}
+
+impl Violation for DoubleDeallocate {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'{name}' deallocated again without being reallocated")
+    }
+}
+
+impl AstRule for DoubleDeallocate {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if node
+            .named_children(&mut node.walk())
+            .any(|child| is_keyword_argument(&child, "stat", text))
+        {
+            return None;
+        }
+
+        // Pairs of (object, variable at the root of the object)
+        let mut deallocated: Vec<(String, String)> = deallocated_objects(node)
+            .iter()
+            .filter_map(|object| Some((object_key(object, text)?, root_name(object, text)?)))
+            .collect();
+
+        let mut violations = Vec::new();
+        let mut sibling = node.next_named_sibling();
+        while let Some(statement) = sibling {
+            if deallocated.is_empty() {
+                break;
+            }
+            sibling = statement.next_named_sibling();
+
+            match statement.kind() {
+                "comment" => continue,
+                "deallocate_statement" => {
+                    for object in deallocated_objects(&statement) {
+                        let (Some(name), Some(key)) =
+                            (object.to_text(text), object_key(&object, text))
+                        else {
+                            continue;
+                        };
+                        if let Some(index) = deallocated.iter().position(|(seen, _)| *seen == key) {
+                            deallocated.remove(index);
+                            violations.push(Diagnostic::from_node(
+                                Self {
+                                    name: name.to_string(),
+                                },
+                                &object,
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    // Any other use of the variable might reallocate or
+                    // re-associate it
+                    let used: Vec<String> = statement
+                        .named_descendants()
+                        .filter(|child| child.kind() == "identifier")
+                        .filter_map(|child| Some(child.to_text(text)?.to_lowercase()))
+                        .collect();
+                    deallocated.retain(|(_, root)| !used.contains(root));
+                }
+            }
+        }
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["deallocate_statement"]
+    }
+}
+
+/// The objects being deallocated by a `deallocate` statement
+fn deallocated_objects<'a>(node: &Node<'a>) -> Vec<Node<'a>> {
+    node.named_children(&mut node.walk())
+        .filter(|child| !matches!(child.kind(), "keyword_argument" | "comment"))
+        .collect()
+}
+
+/// Normalised text of an object, so that `a % x` and `A%X` compare equal
+fn object_key(object: &Node, src: &str) -> Option<String> {
+    Some(
+        object
+            .to_text(src)?
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase(),
+    )
+}
+
+/// The variable at the root of objects like `x(n)` or `a%x`
+fn root_name(object: &Node, src: &str) -> Option<String> {
+    let mut object = *object;
+    while object.kind() != "identifier" {
+        object = object.named_child(0)?;
+    }
+    Some(object.to_text(src)?.to_lowercase())
+}
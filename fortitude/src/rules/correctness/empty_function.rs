@@ -0,0 +1,86 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for functions that contain only declarations, with no executable
+/// statements.
+///
+/// ## Why is this bad?
+/// A function without any executable statements never sets its result, so
+/// the value it returns is undefined. This is usually a stub that was never
+/// finished. Function declarations in interface blocks are not checked.
+///
+/// ## Example
+/// ```f90
+/// integer function answer()
+///   implicit none (type, external)
+/// end function answer
+/// ```
+#[violation]
+pub struct EmptyFunctionBody {
+    name: String,
+}
+
+impl Violation for EmptyFunctionBody {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("Function '{name}' has no executable statements, so its result is undefined")
+    }
+}
+
+/// Statements that may appear in a function without doing anything at runtime
+const SPECIFICATION_KINDS: &[&str] = &[
+    "function_statement",
+    "end_function_statement",
+    "comment",
+    "use_statement",
+    "import_statement",
+    "implicit_statement",
+    "variable_declaration",
+    "variable_modification",
+    "parameter_statement",
+    "derived_type_definition",
+    "interface",
+    "enum",
+    "common_statement",
+    "equivalence_statement",
+    "namelist_statement",
+    "public_statement",
+    "private_statement",
+    "internal_procedures",
+];
+
+impl AstRule for EmptyFunctionBody {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if node.parent()?.kind() == "interface" {
+            return None;
+        }
+
+        let is_empty = node
+            .named_children(&mut node.walk())
+            .all(|child| SPECIFICATION_KINDS.contains(&child.kind()));
+        if !is_empty {
+            return None;
+        }
+
+        let name = node
+            .child_with_name("function_statement")?
+            .child_with_name("name")?;
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_text(src.source_text())?.to_string()
+            },
+            &name
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["function"]
+    }
+}
@@ -0,0 +1,136 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::{regex_captures, regex_is_match};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `error stop` statements without a stop code or message.
+///
+/// ## Why is this bad?
+/// When a program ends with a bare `error stop`, there's no indication of
+/// which check failed or why. A message makes it much easier to find the
+/// cause, particularly when the program has many similar checks:
+///
+/// ```f90
+/// if (n < 0) error stop "solve: negative number of iterations"
+/// ```
+///
+/// ## Example
+/// ```f90
+/// if (n < 0) error stop
+/// ```
+#[violation]
+pub struct ErrorStopWithoutMessage {}
+
+impl Violation for ErrorStopWithoutMessage {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'error stop' without a message")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add a message describing the error, such as 'error stop \"...\"'".to_string())
+    }
+}
+
+impl AstRule for ErrorStopWithoutMessage {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let statement = node.to_text(src.source_text())?.trim();
+        if !regex_is_match!(r"^(?i)error\s*stop\s*(,\s*quiet\s*=.*)?$", statement) {
+            return None;
+        }
+        some_vec!(Diagnostic::from_node(Self {}, node))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["stop_statement"]
+    }
+}
+
+/// ## What it does
+/// Checks for `stop` statements with a non-zero stop code inside modules.
+///
+/// ## Why is this bad?
+/// A non-zero stop code reports failure to the operating system, but it also
+/// ends the program immediately, giving the caller no chance to recover, clean
+/// up, or report the problem in its own way. Procedures in modules are often
+/// used as a library by many different programs, so it's better to return an
+/// error status, for example through an optional `stat` argument, and leave
+/// the decision to stop to the main program.
+///
+/// ## Example
+/// ```f90
+/// subroutine read_config(filename)
+///   ...
+///   if (iostat /= 0) stop 1
+/// end subroutine read_config
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// subroutine read_config(filename, stat)
+///   ...
+///   integer, intent(out) :: stat
+///   ...
+///   if (iostat /= 0) then
+///     stat = iostat
+///     return
+///   end if
+/// end subroutine read_config
+/// ```
+#[violation]
+pub struct StopCodeInModule {
+    code: String,
+    module: String,
+}
+
+impl Violation for StopCodeInModule {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { code, module } = self;
+        format!("'stop {code}' in module '{module}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Return an error status to the caller instead".to_string())
+    }
+}
+
+impl AstRule for StopCodeInModule {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = node.to_text(text)?.trim();
+        let (_, code) = regex_captures!(r"^(?i)stop\s*([+-]?\d+)\s*(?:,.*)?$", statement)?;
+        if code
+            .trim_start_matches(['+', '-'])
+            .chars()
+            .all(|c| c == '0')
+        {
+            return None;
+        }
+
+        let module = node
+            .ancestors()
+            .find(|ancestor| matches!(ancestor.kind(), "module" | "submodule"))?;
+        let module_name = module
+            .child_with_name(&format!("{}_statement", module.kind()))?
+            .child_with_name("name")?
+            .to_text(text)?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                code: code.to_string(),
+                module: module_name.to_string(),
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["stop_statement"]
+    }
+}
@@ -0,0 +1,85 @@
+use crate::ast::FortitudeNode;
+use crate::rules::correctness::read_only_units::{io_unit, open_specifier};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `backspace` and `rewind` statements on a unit that was opened
+/// with `access='direct'` or `access='stream'` in the same scope.
+///
+/// ## Why is this bad?
+/// `backspace` and `rewind` position a file by records, and are only
+/// permitted on files connected for sequential access. Using them on a
+/// direct or stream access file is an error at runtime. Direct access files
+/// are positioned with the `rec=` specifier of `read` and `write`, and stream
+/// access files with `pos=`.
+///
+/// This rule only matches units by their text, so units that are changed
+/// between the `open` and the `backspace` or `rewind` aren't tracked.
+///
+/// ## Example
+/// ```f90
+/// open (10, file="data.bin", access="direct", recl=8)
+/// backspace (10)
+/// ```
+#[violation]
+pub struct NonSequentialFilePositioning {
+    statement: String,
+    unit: String,
+    access: String,
+}
+
+impl Violation for NonSequentialFilePositioning {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            statement,
+            unit,
+            access,
+        } = self;
+        format!("'{statement}' on unit '{unit}', which was opened with access='{access}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { access, .. } = self;
+        if access == "direct" {
+            Some("Use the 'rec=' specifier to choose a record".to_string())
+        } else {
+            Some("Use the 'pos=' specifier to choose a position".to_string())
+        }
+    }
+}
+
+impl AstRule for NonSequentialFilePositioning {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = node.child(0)?.to_text(text)?.to_lowercase();
+        if !matches!(statement.as_str(), "backspace" | "rewind") {
+            return None;
+        }
+
+        let unit = io_unit(node, text)?;
+        let unit_text = unit.to_text(text)?;
+        let access = open_specifier(node, unit_text, "access", text)?.to_lowercase();
+        if !matches!(access.as_str(), "direct" | "stream") {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                statement,
+                unit: unit_text.to_string(),
+                access,
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["file_position_statement"]
+    }
+}
@@ -0,0 +1,76 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for functions whose `result` variable has the same name as one of
+/// their dummy arguments.
+///
+/// ## Why is this bad?
+/// The result variable and the dummy arguments are separate entities, so they
+/// can't share a name, and the code will be rejected by the compiler. This is
+/// usually a copy-paste mistake, or an argument that was meant to be renamed.
+///
+/// ## Example
+/// ```f90
+/// real function scale(r) result(r)
+///   real, intent(in) :: r
+///   ...
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real function scale(x) result(r)
+///   real, intent(in) :: x
+///   ...
+/// ```
+#[violation]
+pub struct ResultNamedAsArgument {
+    name: String,
+}
+
+impl Violation for ResultNamedAsArgument {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("Function result '{name}' has the same name as a dummy argument")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Rename the result or the argument".to_string())
+    }
+}
+
+impl AstRule for ResultNamedAsArgument {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let result = node
+            .child_with_name("function_result")?
+            .child_with_name("identifier")?;
+        let name = result.to_text(text)?;
+
+        let parameters = node.child_by_field_name("parameters")?;
+        if !parameters
+            .named_children(&mut parameters.walk())
+            .filter_map(|param| param.to_text(text))
+            .any(|param| param.eq_ignore_ascii_case(name))
+        {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string()
+            },
+            &result
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["function_statement"]
+    }
+}
@@ -0,0 +1,117 @@
+use crate::ast::{declarator_name, find_variable_declaration, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for local variables in internal and module procedures that have the
+/// same name as a variable in the host scope.
+///
+/// ## Why is this bad?
+/// Procedures after a `contains` statement can see the variables of their
+/// host. Declaring a local variable with the same name silently hides the host
+/// variable for the whole procedure, so assignments meant for the host
+/// variable are lost, and readers may confuse the two.
+///
+/// Dummy arguments and function results are not reported.
+///
+/// ## Example
+/// ```f90
+/// subroutine host()
+///   real :: x
+///   call inner()
+/// contains
+///   subroutine inner()
+///     real :: x  ! Hides `host`'s `x`
+///     x = 1.0
+///   end subroutine inner
+/// end subroutine host
+/// ```
+#[violation]
+pub struct ShadowedHostVariable {
+    name: String,
+    host: String,
+}
+
+impl Violation for ShadowedHostVariable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, host } = self;
+        format!("Local variable '{name}' shadows a variable of the same name in '{host}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Rename the local variable".to_string())
+    }
+}
+
+impl AstRule for ShadowedHostVariable {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if node.parent()?.kind() != "internal_procedures" {
+            return None;
+        }
+
+        let text = src.source_text();
+        let statement = node.child_with_name(&format!("{}_statement", node.kind()))?;
+        let mut arguments: Vec<String> = statement
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .named_children(&mut parameters.walk())
+                    .filter_map(|param| param.to_text(text))
+                    .map(|param| param.to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if node.kind() == "function" {
+            // The result has the function's name unless it's given explicitly
+            let result = statement
+                .child_with_name("function_result")
+                .and_then(|result| result.child_with_name("identifier"))
+                .or_else(|| statement.child_with_name("name"));
+            arguments.extend(
+                result
+                    .and_then(|result| result.to_text(text))
+                    .map(|result| result.to_lowercase()),
+            );
+        }
+
+        let violations = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .flat_map(|decl| {
+                decl.children_by_field_name("declarator", &mut decl.walk())
+                    .collect::<Vec<_>>()
+            })
+            .filter_map(|declarator| {
+                let name = declarator_name(&declarator, text)?;
+                if arguments.contains(&name.to_lowercase()) {
+                    return None;
+                }
+                // Start searching from the host
+                let host_declaration = find_variable_declaration(node, name, text)?;
+                let host = scoping_unit(&host_declaration)?;
+                let host_name = host
+                    .child_with_name(&format!("{}_statement", host.kind()))?
+                    .child_with_name("name")?
+                    .to_text(text)?;
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                        host: host_name.to_string(),
+                    },
+                    &declarator,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine", "function"]
+    }
+}
@@ -0,0 +1,88 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for named `if` constructs, such as `check: if (x > 0) then`, whose
+/// `end if` statement doesn't repeat the same name.
+///
+/// ## Why is this bad?
+/// The standard requires the `end if` of a named `if` construct to carry the
+/// construct's name. Leaving it off, or giving a different name, is an error
+/// that some compilers only report as a warning, and which makes it harder to
+/// see where the construct ends.
+///
+/// Numeric statement labels on `if` statements don't need to be repeated, and
+/// aren't checked by this rule.
+///
+/// ## Example
+/// ```f90
+/// positive: if (x > 0) then
+///   y = sqrt(x)
+/// end if
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// positive: if (x > 0) then
+///   y = sqrt(x)
+/// end if positive
+/// ```
+#[violation]
+pub struct MismatchedEndIfLabel {
+    label: String,
+}
+
+impl AlwaysFixableViolation for MismatchedEndIfLabel {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { label } = self;
+        format!("'end if' of named 'if' construct should be labelled '{label}'")
+    }
+
+    fn fix_title(&self) -> String {
+        let Self { label } = self;
+        format!("Write as 'end if {label}'")
+    }
+}
+
+impl AstRule for MismatchedEndIfLabel {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        // Skip unlabelled constructs
+        let label = node
+            .child_with_name("block_label_start_expression")?
+            .to_text(text)?
+            .trim_end_matches(':')
+            .trim();
+
+        let end = node.child_with_name("end_if_statement")?;
+        let end_text = end.to_text(text)?;
+        if let Some((_, end_label)) = regex_captures!(r"^(?i)end\s*if\s+(\w+)", end_text) {
+            if end_label.eq_ignore_ascii_case(label) {
+                return None;
+            }
+        }
+
+        // Keep the original spelling of the keyword
+        let (_, keyword) = regex_captures!(r"^(?i)(end\s*if)", end_text)?;
+        let replacement = format!("{keyword} {label}");
+        let fix = Fix::safe_edit(end.edit_replacement(src, replacement));
+        some_vec!(Diagnostic::from_node(
+            Self {
+                label: label.to_string()
+            },
+            &end
+        )
+        .with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["if_statement"]
+    }
+}
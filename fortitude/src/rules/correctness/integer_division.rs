@@ -0,0 +1,107 @@
+use crate::ast::{declaration_type, find_variable_declaration, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for division of two integers where the result is assigned to a
+/// `real` variable.
+///
+/// ## Why is this bad?
+/// Dividing one integer by another performs integer division, which truncates
+/// the result towards zero _before_ it is converted to `real`. This is rarely
+/// what was intended when the result is stored in a floating point variable:
+///
+/// ```f90
+/// real :: x
+/// x = 1/3  ! x is 0.0, not 0.333...
+/// ```
+///
+/// Convert at least one of the operands to `real` first, for example with a
+/// real literal (`1.0/3`) or an explicit conversion (`real(a)/b`).
+///
+/// This rule uses a heuristic to determine the types of the operands, and only
+/// considers integer literals and variables declared as `integer` in the
+/// current or enclosing scope.
+#[violation]
+pub struct IntegerDivisionInRealContext {
+    expression: String,
+}
+
+impl Violation for IntegerDivisionInRealContext {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { expression } = self;
+        format!("Integer division '{expression}' truncated before assignment to real")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Convert an operand to real before dividing".to_string())
+    }
+}
+
+impl AstRule for IntegerDivisionInRealContext {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if node.child_by_field_name("operator")?.to_text(text)? != "/" {
+            return None;
+        }
+
+        let left = node.child_by_field_name("left")?;
+        let right = node.child_by_field_name("right")?;
+        if !is_integer(&left, text) || !is_integer(&right, text) {
+            return None;
+        }
+
+        // Find the statement this expression is part of
+        let mut parent = node.parent()?;
+        while matches!(
+            parent.kind(),
+            "math_expression" | "unary_expression" | "parenthesized_expression"
+        ) {
+            parent = parent.parent()?;
+        }
+        if parent.kind() != "assignment_statement" {
+            return None;
+        }
+
+        let target = parent.child_by_field_name("left")?;
+        if target.kind() != "identifier" {
+            return None;
+        }
+        let declaration = find_variable_declaration(node, target.to_text(text)?, text)?;
+        if !matches!(
+            declaration_type(&declaration, text)?.as_str(),
+            "real" | "double precision" | "complex" | "double complex"
+        ) {
+            return None;
+        }
+
+        let expression = node.to_text(text)?.to_string();
+        some_vec!(Diagnostic::from_node(Self { expression }, node))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["math_expression"]
+    }
+}
+
+/// Heuristic check for whether an operand is an integer: either an integer
+/// literal, or a variable declared as `integer`
+fn is_integer(node: &Node, src: &str) -> bool {
+    match node.kind() {
+        "number_literal" => node
+            .to_text(src)
+            .is_some_and(|literal| regex_is_match!(r"^\d+(_\w+)?$", literal)),
+        "identifier" => node
+            .to_text(src)
+            .and_then(|name| find_variable_declaration(node, name, src))
+            .and_then(|declaration| declaration_type(&declaration, src))
+            .is_some_and(|dtype| dtype == "integer"),
+        _ => false,
+    }
+}
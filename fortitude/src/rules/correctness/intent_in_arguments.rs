@@ -0,0 +1,183 @@
+use crate::ast::{
+    declarator_name, find_variable_declaration, has_attribute, is_keyword_argument, FortitudeNode,
+};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `intent(in)` dummy arguments that are passed to a subroutine
+/// whose corresponding dummy argument is `intent(out)`, `intent(inout)`, or
+/// has no intent.
+///
+/// ## Why is this bad?
+/// An `intent(in)` argument promises not to modify the variable, but the
+/// called subroutine is allowed to change its argument. Compilers reject this
+/// for `intent(out)` and `intent(inout)`, but a dummy argument without an
+/// intent may be modified without any warning, breaking the promise made to
+/// callers.
+///
+/// This rule only checks subroutines defined in the same file, and variables
+/// passed directly as arguments.
+///
+/// ## Example
+/// ```f90
+/// subroutine outer(x)
+///   real, intent(in) :: x
+///   call scale(x)
+/// end subroutine outer
+///
+/// subroutine scale(y)
+///   real :: y
+///   y = 2 * y
+/// end subroutine scale
+/// ```
+#[violation]
+pub struct IntentInPassedAsModifiable {
+    name: String,
+    subroutine: String,
+    dummy: String,
+    intent: Option<String>,
+}
+
+impl Violation for IntentInPassedAsModifiable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            name,
+            subroutine,
+            dummy,
+            intent,
+        } = self;
+        match intent {
+            Some(intent) => format!(
+                "'intent(in)' argument '{name}' passed to '{dummy}' of '{subroutine}', which is 'intent({intent})'"
+            ),
+            None => format!(
+                "'intent(in)' argument '{name}' passed to '{dummy}' of '{subroutine}', which has no intent"
+            ),
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self {
+            subroutine, dummy, ..
+        } = self;
+        Some(format!(
+            "Declare '{dummy}' in '{subroutine}' as 'intent(in)'"
+        ))
+    }
+}
+
+impl AstRule for IntentInPassedAsModifiable {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name = node.named_child(0)?.to_text(text)?;
+
+        let root = node.ancestors().last()?;
+        let callee = root
+            .named_descendants()
+            .filter(|child| child.kind() == "subroutine")
+            .find(|subroutine| {
+                subroutine
+                    .child_with_name("subroutine_statement")
+                    .and_then(|statement| statement.child_with_name("name"))
+                    .and_then(|callee_name| callee_name.to_text(text))
+                    .is_some_and(|callee_name| callee_name.eq_ignore_ascii_case(name))
+            })?;
+        let dummies: Vec<&str> = callee
+            .child_with_name("subroutine_statement")?
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .named_children(&mut parameters.walk())
+                    .filter_map(|parameter| parameter.to_text(text))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let argument_list = node.child_with_name("argument_list")?;
+        let violations = argument_list
+            .named_children(&mut argument_list.walk())
+            .filter(|child| child.kind() != "comment")
+            .enumerate()
+            .filter_map(|(position, argument)| {
+                let (dummy, value) = if argument.kind() == "keyword_argument" {
+                    let dummy = dummies
+                        .iter()
+                        .find(|dummy| is_keyword_argument(&argument, dummy.to_lowercase(), text))?;
+                    (*dummy, argument.child_by_field_name("value")?)
+                } else {
+                    (*dummies.get(position)?, argument)
+                };
+                if value.kind() != "identifier" {
+                    return None;
+                }
+                let variable = value.to_text(text)?;
+                let declaration = find_variable_declaration(node, variable, text)?;
+                if intent(&declaration, text).as_deref() != Some("in") {
+                    return None;
+                }
+
+                // `value` dummies receive a copy, so can't modify the actual argument
+                let dummy_declaration = dummy_declaration(&callee, dummy, text);
+                if dummy_declaration
+                    .as_ref()
+                    .is_some_and(|declaration| has_attribute(declaration, "value", text))
+                {
+                    return None;
+                }
+                let dummy_intent =
+                    dummy_declaration.and_then(|declaration| intent(&declaration, text));
+                if dummy_intent.as_deref() == Some("in") {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: variable.to_string(),
+                        subroutine: name.to_string(),
+                        dummy: dummy.to_string(),
+                        intent: dummy_intent,
+                    },
+                    &value,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine_call"]
+    }
+}
+
+/// The declaration of the dummy argument `name` in `procedure`
+fn dummy_declaration<'a>(procedure: &Node<'a>, name: &str, src: &str) -> Option<Node<'a>> {
+    procedure
+        .named_children(&mut procedure.walk())
+        .filter(|child| child.kind() == "variable_declaration")
+        .find(|declaration| {
+            declaration
+                .children_by_field_name("declarator", &mut declaration.walk())
+                .filter_map(|declarator| declarator_name(&declarator, src))
+                .any(|declared| declared.eq_ignore_ascii_case(name))
+        })
+}
+
+/// The intent of a declaration, such as `in` or `inout`, if it has one
+fn intent(declaration: &Node, src: &str) -> Option<String> {
+    declaration
+        .children_by_field_name("attribute", &mut declaration.walk())
+        .filter_map(|attribute| attribute.to_text(src))
+        .map(|attribute| attribute.to_lowercase().replace(char::is_whitespace, ""))
+        .find_map(|attribute| {
+            attribute
+                .strip_prefix("intent(")?
+                .strip_suffix(')')
+                .map(|intent| intent.to_string())
+        })
+}
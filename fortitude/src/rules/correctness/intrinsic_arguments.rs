@@ -0,0 +1,183 @@
+use crate::ast::{declaration_type, find_variable_declaration, is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// Intrinsic procedures with arguments that must be integers, along with the
+/// name and position of each argument.
+const INTEGER_ARGUMENTS: &[(&str, &[(&str, usize)])] = &[
+    ("reshape", &[("shape", 1), ("order", 3)]),
+    ("size", &[("dim", 1), ("kind", 2)]),
+    ("lbound", &[("dim", 1), ("kind", 2)]),
+    ("ubound", &[("dim", 1), ("kind", 2)]),
+    ("shape", &[("kind", 1)]),
+    ("spread", &[("dim", 1), ("ncopies", 2)]),
+    ("cshift", &[("shift", 1), ("dim", 2)]),
+    ("sum", &[("dim", 1)]),
+    ("product", &[("dim", 1)]),
+    ("maxval", &[("dim", 1)]),
+    ("minval", &[("dim", 1)]),
+    ("maxloc", &[("dim", 1)]),
+    ("minloc", &[("dim", 1)]),
+    ("any", &[("dim", 1)]),
+    ("all", &[("dim", 1)]),
+    ("count", &[("dim", 1)]),
+    ("int", &[("kind", 1)]),
+    ("nint", &[("kind", 1)]),
+    ("real", &[("kind", 1)]),
+    ("logical", &[("kind", 1)]),
+    ("selected_real_kind", &[("p", 0), ("r", 1), ("radix", 2)]),
+    ("selected_int_kind", &[("r", 0)]),
+];
+
+/// ## What it does
+/// Checks for real values passed to intrinsic procedure arguments that must be
+/// integers, such as the `shape` argument of `reshape`, or `dim` and `kind`
+/// arguments.
+///
+/// ## Why is this bad?
+/// These arguments are required to be integers. Most compilers will reject a
+/// real value here, while others may silently truncate it, so the program
+/// either fails to build or behaves differently between compilers.
+///
+/// This rule only considers real literals and variables declared as `real` in
+/// the current or an enclosing scope.
+///
+/// ## Example
+/// ```f90
+/// matrix = reshape(array, [3.0, 4.0])
+/// total = sum(matrix, dim=2.0)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// matrix = reshape(array, [3, 4])
+/// total = sum(matrix, dim=2)
+/// ```
+#[violation]
+pub struct RealIntrinsicArgument {
+    value: String,
+    argument: String,
+    intrinsic: String,
+}
+
+impl Violation for RealIntrinsicArgument {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            value,
+            argument,
+            intrinsic,
+        } = self;
+        format!("real value '{value}' passed to integer argument '{argument}' of '{intrinsic}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use an integer value".to_string())
+    }
+}
+
+impl AstRule for RealIntrinsicArgument {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name = node.child_with_name("identifier")?.to_text(text)?;
+        let intrinsic = name.to_lowercase();
+        let (_, arguments) = INTEGER_ARGUMENTS
+            .iter()
+            .find(|(intrinsic_name, _)| *intrinsic_name == intrinsic)?;
+        // Skip local variables or arrays that shadow the intrinsic
+        if find_variable_declaration(node, name, text).is_some() {
+            return None;
+        }
+
+        let argument_list = node.child_with_name("argument_list")?;
+        let actual_arguments: Vec<Node> = argument_list
+            .named_children(&mut argument_list.walk())
+            .filter(|child| child.kind() != "comment")
+            .collect();
+
+        let violations = arguments
+            .iter()
+            .filter_map(|(keyword, position)| {
+                let value = actual_arguments
+                    .iter()
+                    .find(|argument| is_keyword_argument(argument, keyword, text))
+                    .and_then(|argument| argument.child_by_field_name("value"))
+                    .or_else(|| {
+                        actual_arguments
+                            .get(*position)
+                            .filter(|argument| argument.kind() != "keyword_argument")
+                            .copied()
+                    })?;
+                Some((keyword, value))
+            })
+            .flat_map(|(keyword, value)| {
+                real_values(&value, text)
+                    .into_iter()
+                    .filter_map(|real| {
+                        Some(Diagnostic::from_node(
+                            Self {
+                                value: real.to_text(text)?.to_string(),
+                                argument: keyword.to_string(),
+                                intrinsic: intrinsic.clone(),
+                            },
+                            &real,
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["call_expression"]
+    }
+}
+
+/// Returns the parts of `node` that are real: either `node` itself, or the real
+/// elements of an array constructor
+fn real_values<'a>(node: &Node<'a>, src: &str) -> Vec<Node<'a>> {
+    match node.kind() {
+        "array_literal" => {
+            let is_real_constructor = node
+                .child_with_name("intrinsic_type")
+                .and_then(|type_| type_.to_text(src))
+                .is_some_and(|type_| {
+                    matches!(type_.to_lowercase().as_str(), "real" | "double precision")
+                });
+            if is_real_constructor {
+                return vec![*node];
+            }
+            node.named_children(&mut node.walk())
+                .filter(|element| is_real(element, src))
+                .collect()
+        }
+        _ if is_real(node, src) => vec![*node],
+        _ => vec![],
+    }
+}
+
+/// Heuristic check for whether a value is real: either a real literal, or a
+/// variable declared as `real`
+fn is_real(node: &Node, src: &str) -> bool {
+    match node.kind() {
+        "number_literal" => node.to_text(src).is_some_and(|literal| {
+            regex_is_match!(
+                r"^((\d+\.\d*|\d*\.\d+)([eEdD][+-]?\d+)?|\d+[eEdD][+-]?\d+)(_\w+)?$",
+                literal
+            )
+        }),
+        "identifier" => node
+            .to_text(src)
+            .and_then(|name| find_variable_declaration(node, name, src))
+            .and_then(|declaration| declaration_type(&declaration, src))
+            .is_some_and(|dtype| matches!(dtype.as_str(), "real" | "double precision")),
+        _ => false,
+    }
+}
@@ -0,0 +1,98 @@
+use crate::ast::FortitudeNode;
+use crate::rules::modules::use_statements::INTRINSIC_MODULES;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `use` statements without an `intrinsic` or `non_intrinsic`
+/// modifier, where the module name refers both to an intrinsic module and to a
+/// module defined in the same file.
+///
+/// ## Why is this bad?
+/// When a user module has the same name as an intrinsic module, a plain `use`
+/// statement is ambiguous, and which module is picked up depends on the
+/// compiler and its search paths. Stating `use, intrinsic ::` or
+/// `use, non_intrinsic ::` makes the choice explicit.
+///
+/// This rule can currently only see modules defined in the file being checked.
+///
+/// ## Example
+/// ```f90
+/// module iso_c_binding
+///   ...
+/// end module iso_c_binding
+///
+/// module user
+///   use iso_c_binding  ! Which one?
+/// end module user
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// module user
+///   use, non_intrinsic :: iso_c_binding
+/// end module user
+/// ```
+#[violation]
+pub struct AmbiguousIntrinsicModule {
+    name: String,
+}
+
+impl Violation for AmbiguousIntrinsicModule {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'use {name}' is ambiguous, a user module shadows the intrinsic module")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add 'intrinsic' or 'non_intrinsic'".to_string())
+    }
+}
+
+impl AstRule for AmbiguousIntrinsicModule {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name = node.child_with_name("module_name")?.to_text(text)?;
+        let lowercase_name = name.to_lowercase();
+        if !INTRINSIC_MODULES.contains(&lowercase_name.as_str()) {
+            return None;
+        }
+
+        let has_nature = node
+            .children(&mut node.walk())
+            .filter_map(|child| child.to_text(text))
+            .any(|child| matches!(child.to_lowercase().as_str(), "intrinsic" | "non_intrinsic"));
+        if has_nature {
+            return None;
+        }
+
+        let root = node.ancestors().last()?;
+        let is_shadowed = root
+            .named_children(&mut root.walk())
+            .filter(|child| child.kind() == "module")
+            .filter_map(|module| {
+                let statement = module.child_with_name("module_statement")?;
+                statement.child_with_name("name")?.to_text(text)
+            })
+            .any(|module_name| module_name.to_lowercase() == lowercase_name);
+        if !is_shadowed {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string()
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["use_statement"]
+    }
+}
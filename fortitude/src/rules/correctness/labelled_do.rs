@@ -0,0 +1,81 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for labelled `do` loops, such as `do 100 i = 1, n`, that are
+/// terminated by an unlabelled `end do` statement.
+///
+/// ## Why is this bad?
+/// The label in a labelled `do` statement names the statement that ends the
+/// loop, so that statement should carry the same label. Ending such a loop
+/// with a bare `end do` is accepted by some compilers, but it is not standard
+/// and makes it unclear which statement the label refers to. Either label the
+/// `end do`, or better, drop the label from the `do` statement entirely.
+///
+/// ## Example
+/// ```f90
+/// do 100 i = 1, 10
+///   x = x + i
+/// end do
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// do i = 1, 10
+///   x = x + i
+/// end do
+/// ```
+#[violation]
+pub struct UnlabelledEndDo {
+    label: String,
+}
+
+impl Violation for UnlabelledEndDo {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { label } = self;
+        format!("Labelled 'do {label}' loop terminated by unlabelled 'end do'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { label } = self;
+        Some(format!("Remove label '{label}' from the 'do' statement"))
+    }
+}
+
+impl AstRule for UnlabelledEndDo {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let (_, label) = regex_captures!(r"^(?i)(?:\w+\s*:\s*)?do\s+(\d+)", node.to_text(text)?)?;
+
+        let end = node.child_with_name("end_do_loop_statement")?;
+        // The terminating label may or may not be part of the node, so check
+        // everything from the start of the line
+        let line_start = text[..end.start_byte()]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let end_text = &text[line_start..end.end_byte()];
+        if let Some((_, end_label)) = regex_captures!(r"^\s*(\d+)", end_text) {
+            if end_label.trim_start_matches('0') == label.trim_start_matches('0') {
+                return None;
+            }
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                label: label.to_string()
+            },
+            &end
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["do_loop_statement"]
+    }
+}
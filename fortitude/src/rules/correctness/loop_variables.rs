@@ -0,0 +1,70 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for assignments to the loop variable inside the body of a `do` loop.
+///
+/// ## Why is this bad?
+/// The Fortran standard forbids modifying the variable of a `do` loop while
+/// the loop is executing, and the behaviour of a program that does so is
+/// undefined. Compilers are free to assume the loop variable is only updated
+/// by the loop itself, so the loop may run a different number of times to
+/// what was intended, or not at all.
+///
+/// If the number of iterations needs to change at runtime, use a `do while`
+/// loop or an `exit` statement instead.
+///
+/// ## Example
+/// ```f90
+/// do i = 1, 10
+///   i = i + 1  ! undefined behaviour
+/// end do
+/// ```
+#[violation]
+pub struct ModifiedLoopVariable {
+    name: String,
+}
+
+impl Violation for ModifiedLoopVariable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("Loop variable '{name}' modified inside 'do' loop")
+    }
+}
+
+impl AstRule for ModifiedLoopVariable {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let src = src.source_text();
+        // Only counted loops have a loop variable
+        let name = node
+            .child_with_name("loop_control_expression")?
+            .child_with_name("identifier")?
+            .to_text(src)?
+            .to_lowercase();
+
+        let violations: Vec<Diagnostic> = node
+            .named_descendants()
+            .filter(|child| child.kind() == "assignment_statement")
+            .filter(|assignment| {
+                assignment
+                    .child_by_field_name("left")
+                    .filter(|left| left.kind() == "identifier")
+                    .and_then(|left| left.to_text(src))
+                    .is_some_and(|left| left.to_lowercase() == name)
+            })
+            .map(|assignment| Diagnostic::from_node(Self { name: name.clone() }, &assignment))
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["do_loop_statement"]
+    }
+}
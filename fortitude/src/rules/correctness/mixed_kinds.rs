@@ -0,0 +1,125 @@
+use crate::ast::{declaration_type, find_variable_declaration, FortitudeNode};
+use crate::rules::typing::literal_kinds::iso_fortran_env_param;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for arithmetic between a real literal and a real variable or
+/// literal of a different kind, such as `x + 1.0_real64` where `x` is
+/// `real(real32)`.
+///
+/// ## Why is this bad?
+/// Mixed-kind arithmetic silently converts the lower precision operand to the
+/// higher precision kind. The result is then often converted straight back
+/// when it is assigned, so the extra precision of the literal is wasted, or
+/// worse, gives the impression of more accuracy than there is. It usually
+/// means the literal's kind was copied from elsewhere, or the variable's kind
+/// was changed without updating the literals used with it.
+///
+/// This rule only considers real literals with a kind suffix, and variables
+/// declared as `real` in the current or enclosing scope. Kinds are compared by
+/// name, so different names for the same kind, such as `dp` and `real64`,
+/// will be reported.
+///
+/// ## Example
+/// ```f90
+/// real(real32) :: x
+/// x = x + 1.0_real64
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real(real32) :: x
+/// x = x + 1.0_real32
+/// ```
+#[violation]
+pub struct MixedRealKinds {
+    left: String,
+    right: String,
+}
+
+impl Violation for MixedRealKinds {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { left, right } = self;
+        format!("Arithmetic between real kinds '{left}' and '{right}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use literals of the same kind as the variables they are used with".to_string())
+    }
+}
+
+impl AstRule for MixedRealKinds {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if !matches!(
+            node.child_by_field_name("operator")?.to_text(text)?,
+            "+" | "-" | "*" | "/"
+        ) {
+            return None;
+        }
+
+        let left = node.child_by_field_name("left")?;
+        let right = node.child_by_field_name("right")?;
+        if left.kind() != "number_literal" && right.kind() != "number_literal" {
+            return None;
+        }
+
+        let left_kind = real_kind(&left, text)?;
+        let right_kind = real_kind(&right, text)?;
+        if left_kind == right_kind {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                left: left_kind,
+                right: right_kind,
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["math_expression"]
+    }
+}
+
+/// The lowercase kind of an operand, if it is a real literal with a kind
+/// suffix or a variable declared as `real` with an explicit kind. Number
+/// kinds are converted to their `iso_fortran_env` name where possible.
+fn real_kind(node: &Node, src: &str) -> Option<String> {
+    let kind = match node.kind() {
+        "number_literal" => {
+            let (_, _, kind) = regex_captures!(
+                r"^(?i)(\d+\.\d*|\.\d+|\d+\.?\d*e[+-]?\d+)_(\w+)$",
+                node.to_text(src)?
+            )?;
+            kind.to_lowercase()
+        }
+        "identifier" => {
+            let declaration = find_variable_declaration(node, node.to_text(src)?, src)?;
+            if declaration_type(&declaration, src)? != "real" {
+                return None;
+            }
+            let kind = declaration
+                .child_by_field_name("type")?
+                .child_by_field_name("kind")?;
+            let (_, kind) =
+                regex_captures!(r"^\(\s*(?i:kind\s*=\s*)?(\w+)\s*\)$", kind.to_text(src)?)?;
+            kind.to_lowercase()
+        }
+        _ => return None,
+    };
+
+    if let Ok(literal) = kind.parse::<u8>() {
+        return Some(iso_fortran_env_param("real", literal).unwrap_or(kind));
+    }
+    Some(kind)
+}
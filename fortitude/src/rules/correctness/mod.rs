@@ -0,0 +1,211 @@
+pub mod allocate_mold;
+pub mod associate_constants;
+pub mod associate_names;
+pub mod c_pointers;
+pub mod coarrays;
+pub mod concurrent_branching;
+pub mod double_deallocate;
+pub mod empty_function;
+pub mod error_stop;
+pub mod file_positioning;
+pub mod function_result;
+pub mod host_association;
+pub mod if_labels;
+pub mod integer_division;
+pub mod intent_in_arguments;
+pub mod intrinsic_arguments;
+pub mod intrinsic_modules;
+pub mod labelled_do;
+pub mod loop_variables;
+pub mod mixed_kinds;
+pub mod module_output;
+pub mod move_alloc;
+pub mod namelist_names;
+pub mod optional_arguments;
+pub mod pointer_intent;
+pub mod procedure_pointers;
+pub mod protected;
+pub mod random_seed;
+pub mod read_only_units;
+pub mod read_parameters;
+pub mod real_literal_range;
+pub mod real_literals;
+pub mod real_subscripts;
+pub mod select_type;
+pub mod sequence_types;
+pub mod status_inquiry;
+pub mod value_intent;
+pub mod volatile;
+pub mod zero_length_character;
+
+#[cfg(test)]
+mod tests {
+    use std::convert::AsRef;
+    use std::path::Path;
+
+    use anyhow::Result;
+    use insta::assert_snapshot;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::settings::{FortranStandard, NamePatternSet, Settings};
+    use crate::test::test_path;
+
+    #[test_case(Rule::ModifiedLoopVariable, Path::new("C541.f90"))]
+    #[test_case(Rule::IntegerDivisionInRealContext, Path::new("C551.f90"))]
+    #[test_case(Rule::UnlabelledEndDo, Path::new("C561.f90"))]
+    #[test_case(Rule::IncompleteRealLiteral, Path::new("C571.f90"))]
+    #[test_case(Rule::RealLiteralWhereIntegerExpected, Path::new("C581.f90"))]
+    #[test_case(Rule::AmbiguousIntrinsicModule, Path::new("C591.f90"))]
+    #[test_case(Rule::UnjustifiedVolatile, Path::new("C601.f90"))]
+    #[test_case(Rule::ProtectedVariableModified, Path::new("C611.f90"))]
+    #[test_case(Rule::ShadowedHostVariable, Path::new("C631.f90"))]
+    #[test_case(Rule::EmptyFunctionBody, Path::new("C641.f90"))]
+    #[test_case(Rule::UncheckedCPointer, Path::new("C651.f90"))]
+    #[test_case(Rule::MismatchedEndIfLabel, Path::new("C661.f90"))]
+    #[test_case(Rule::AssociateShadowsUseName, Path::new("C671.f90"))]
+    #[test_case(Rule::MismatchedAllocateMold, Path::new("C681.f90"))]
+    #[test_case(Rule::BranchInConcurrentLoop, Path::new("C691.f90"))]
+    #[test_case(Rule::WriteToReadOnlyUnit, Path::new("C701.f90"))]
+    #[test_case(Rule::ZeroLengthCharacter, Path::new("C711.f90"))]
+    #[test_case(Rule::RealLiteralOutOfRange, Path::new("C721.f90"))]
+    #[test_case(Rule::RealIntrinsicArgument, Path::new("C741.f90"))]
+    #[test_case(Rule::CoarrayDeclaration, Path::new("C751.f90"))]
+    #[test_case(Rule::RandomNumberWithoutSeed, Path::new("C761.f90"))]
+    #[test_case(Rule::NonSequentialFilePositioning, Path::new("C771.f90"))]
+    #[test_case(Rule::NonAllocatableMoveAlloc, Path::new("C781.f90"))]
+    #[test_case(Rule::ConstantAssociateSelector, Path::new("C791.f90"))]
+    #[test_case(Rule::ErrorStopWithoutMessage, Path::new("C801.f90"))]
+    #[test_case(Rule::AllocatedNonAllocatable, Path::new("C821.f90"))]
+    #[test_case(Rule::AssociatedNonPointer, Path::new("C831.f90"))]
+    #[test_case(Rule::IntentInPassedAsModifiable, Path::new("C841.f90"))]
+    #[test_case(Rule::OutputInModule, Path::new("C851.f90"))]
+    #[test_case(Rule::NegativeCharacterLength, Path::new("C861.f90"))]
+    #[test_case(Rule::DoubleDeallocate, Path::new("C891.f90"))]
+    #[test_case(Rule::StopCodeInModule, Path::new("C901.f90"))]
+    #[test_case(Rule::ResultNamedAsArgument, Path::new("C911.f90"))]
+    #[test_case(Rule::TypeIsWithExtensions, Path::new("C921.f90"))]
+    #[test_case(Rule::NamelistShadowsVariable, Path::new("C931.f90"))]
+    #[test_case(Rule::ReadIntoParameter, Path::new("C941.f90"))]
+    #[test_case(Rule::SequenceTypeInBindC, Path::new("C951.f90"))]
+    #[test_case(Rule::PureProcedureToImpurePointer, Path::new("C961.f90"))]
+    #[test_case(Rule::MixedRealKinds, Path::new("C971.f90"))]
+    #[test_case(Rule::ErrorOutputInModule, Path::new("C981.f90"))]
+    #[test_case(Rule::UnguardedOptionalInPure, Path::new("C991.f90"))]
+    #[test_case(Rule::ValueWithOutputIntent, Path::new("C1001.f90"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &Settings::default(),
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::IncompleteRealLiteral, Path::new("C571_leading_zero.f90"))]
+    fn incomplete_real_literal_leading_zero(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            require_real_leading_zero: true,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::UnjustifiedVolatile, Path::new("C601_allowed_names.f90"))]
+    fn unjustified_volatile_allowed_names(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            allowed_volatile_names: NamePatternSet::try_from_iter(["flag_*"])?,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::ErrorOutputInModule, Path::new("C981_allowed_modules.f90"))]
+    fn error_output_in_module_allowed_modules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            allowed_logging_modules: NamePatternSet::try_from_iter(["log*"])?,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::CoarrayDeclaration, Path::new("C751.f90"))]
+    fn coarray_declaration_with_support(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            coarray_support: true,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::RandomNumberWithoutSeed, Path::new("C761_seeded.f90"))]
+    fn random_number_with_seed(rule_code: Rule, path: &Path) -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &Settings::default(),
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::PointerIntent, Path::new("C881.f90"))]
+    fn rules_f95(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            target_std: FortranStandard::F95,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::PointerIntent, Path::new("C881.f90"))]
+    fn rules_f2003(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            target_std: FortranStandard::F2003,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("correctness").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+}
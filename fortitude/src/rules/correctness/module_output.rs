@@ -0,0 +1,192 @@
+use crate::ast::FortitudeNode;
+use crate::rules::correctness::read_only_units::io_unit;
+use crate::rules::style::kind_parameters::iso_fortran_env_name;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `print` statements, and `write` statements to the default unit
+/// `*`, inside modules.
+///
+/// ## Why is this bad?
+/// Modules are usually library code, used by many different programs. Output
+/// written directly to the terminal can't be redirected, silenced, or
+/// formatted by the program using the library, and may interfere with its own
+/// output. Instead, return information through arguments, write to a unit
+/// passed in by the caller, or use a logging abstraction that the program can
+/// configure.
+///
+/// ## Example
+/// ```f90
+/// module solver
+///   implicit none
+/// contains
+///   subroutine solve(x)
+///     real, intent(inout) :: x
+///     print *, "solving..."
+///   end subroutine solve
+/// end module solver
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// module solver
+///   implicit none
+/// contains
+///   subroutine solve(x, log_unit)
+///     real, intent(inout) :: x
+///     integer, intent(in) :: log_unit
+///     write (log_unit, *) "solving..."
+///   end subroutine solve
+/// end module solver
+/// ```
+#[violation]
+pub struct OutputInModule {
+    statement: String,
+    module: String,
+}
+
+impl Violation for OutputInModule {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { statement, module } = self;
+        format!("'{statement}' to standard output in module '{module}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some(
+            "Pass output through an argument, a unit chosen by the caller, or a logger".to_string(),
+        )
+    }
+}
+
+impl AstRule for OutputInModule {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = if node.kind() == "write_statement" {
+            if io_unit(node, text)?.to_text(text)? != "*" {
+                return None;
+            }
+            "write"
+        } else {
+            "print"
+        };
+
+        let module = node
+            .ancestors()
+            .find(|ancestor| matches!(ancestor.kind(), "module" | "submodule"))?;
+        let module_name = module
+            .child_with_name(&format!("{}_statement", module.kind()))?
+            .child_with_name("name")?
+            .to_text(text)?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                statement: statement.to_string(),
+                module: module_name.to_string(),
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["print_statement", "write_statement"]
+    }
+}
+
+/// ## What it does
+/// Checks for `write` statements to standard error, either unit `0` or
+/// `error_unit` from `iso_fortran_env`, inside modules.
+///
+/// Modules that are meant to write to standard error, such as logging
+/// modules, can be allowed using `--allowed-logging-modules`, which takes a
+/// list of glob patterns.
+///
+/// ## Why is this bad?
+/// Like other output from library code, messages written straight to standard
+/// error can't be intercepted, silenced, or redirected by the program using
+/// the library. Errors are better reported through a `stat` or error message
+/// argument, so that the caller can decide how to handle them, or through a
+/// logging module that the program can configure.
+///
+/// ## Example
+/// ```f90
+/// module solver
+///   use, intrinsic :: iso_fortran_env, only: error_unit
+///   implicit none
+/// contains
+///   subroutine solve(x)
+///     real, intent(inout) :: x
+///     if (x < 0) write (error_unit, *) "x is negative"
+///   end subroutine solve
+/// end module solver
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// module solver
+///   implicit none
+/// contains
+///   subroutine solve(x, stat)
+///     real, intent(inout) :: x
+///     integer, intent(out) :: stat
+///     stat = 0
+///     if (x < 0) stat = 1
+///   end subroutine solve
+/// end module solver
+/// ```
+#[violation]
+pub struct ErrorOutputInModule {
+    module: String,
+}
+
+impl Violation for ErrorOutputInModule {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { module } = self;
+        format!("'write' to standard error in module '{module}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Report errors through an argument, or a logger".to_string())
+    }
+}
+
+impl AstRule for ErrorOutputInModule {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let unit = io_unit(node, text)?.to_text(text)?;
+        let is_error_unit = unit == "0"
+            || iso_fortran_env_name(node, "error_unit", text)
+                .is_some_and(|error_unit| unit.eq_ignore_ascii_case(&error_unit));
+        if !is_error_unit {
+            return None;
+        }
+
+        let module = node
+            .ancestors()
+            .find(|ancestor| matches!(ancestor.kind(), "module" | "submodule"))?;
+        let module_name = module
+            .child_with_name(&format!("{}_statement", module.kind()))?
+            .child_with_name("name")?
+            .to_text(text)?;
+        if settings.allowed_logging_modules.matches(module_name) {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                module: module_name.to_string(),
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["write_statement"]
+    }
+}
@@ -0,0 +1,109 @@
+use crate::ast::{find_variable_declaration, has_attribute, is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for calls to `move_alloc` where the `from` or `to` argument is a
+/// variable that isn't declared `allocatable`.
+///
+/// ## Why is this bad?
+/// `move_alloc` transfers an allocation from one allocatable variable to
+/// another, so both arguments must be `allocatable`. Compilers will reject
+/// other variables, but the error message doesn't always make the cause clear,
+/// particularly when the declaration is far from the call.
+///
+/// This rule only considers variables declared in the current or an enclosing
+/// scope.
+///
+/// ## Example
+/// ```f90
+/// real, allocatable :: work(:)
+/// real :: result(10)
+/// call move_alloc(work, result)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real, allocatable :: work(:)
+/// real, allocatable :: result(:)
+/// call move_alloc(work, result)
+/// ```
+#[violation]
+pub struct NonAllocatableMoveAlloc {
+    argument: String,
+    name: String,
+}
+
+impl Violation for NonAllocatableMoveAlloc {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { argument, name } = self;
+        format!("'{argument}' argument '{name}' of 'move_alloc' is not allocatable")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name, .. } = self;
+        Some(format!("Declare '{name}' with the 'allocatable' attribute"))
+    }
+}
+
+impl AstRule for NonAllocatableMoveAlloc {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if !node
+            .named_child(0)?
+            .to_text(text)?
+            .eq_ignore_ascii_case("move_alloc")
+        {
+            return None;
+        }
+
+        let argument_list = node.child_with_name("argument_list")?;
+        let arguments: Vec<Node> = argument_list
+            .named_children(&mut argument_list.walk())
+            .filter(|child| child.kind() != "comment")
+            .collect();
+
+        let violations = [("from", 0), ("to", 1)]
+            .iter()
+            .filter_map(|(keyword, position)| {
+                let value = arguments
+                    .iter()
+                    .find(|argument| is_keyword_argument(argument, keyword, text))
+                    .and_then(|argument| argument.child_by_field_name("value"))
+                    .or_else(|| {
+                        arguments
+                            .get(*position)
+                            .filter(|argument| argument.kind() != "keyword_argument")
+                            .copied()
+                    })?;
+                // Components such as `a%x` would need the type definition
+                if value.kind() != "identifier" {
+                    return None;
+                }
+                let name = value.to_text(text)?;
+                let declaration = find_variable_declaration(node, name, text)?;
+                if has_attribute(&declaration, "allocatable", text) {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        argument: keyword.to_string(),
+                        name: name.to_string(),
+                    },
+                    &value,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine_call"]
+    }
+}
@@ -0,0 +1,106 @@
+use crate::ast::{declarator_name, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `namelist` group names that are also the name of a variable
+/// declared in the same scope.
+///
+/// ## Why is this bad?
+/// Namelist group names and variable names are in different classes, so some
+/// compilers will accept the same name for both. It is confusing to read
+/// though, as `read(unit, nml=foo)` and `read(unit, *) foo` do very different
+/// things, and other compilers will reject it outright.
+///
+/// ## Example
+/// ```f90
+/// real :: config
+/// namelist /config/ config, tolerance
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real :: config
+/// namelist /config_nml/ config, tolerance
+/// ```
+#[violation]
+pub struct NamelistShadowsVariable {
+    name: String,
+}
+
+impl Violation for NamelistShadowsVariable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("namelist group '{name}' has the same name as a variable")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Rename the namelist group".to_string())
+    }
+}
+
+impl AstRule for NamelistShadowsVariable {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let unit = scoping_unit(node)?;
+        let variables: Vec<String> = unit
+            .named_children(&mut unit.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .flat_map(|decl| {
+                decl.children_by_field_name("declarator", &mut decl.walk())
+                    .filter_map(|declarator| declarator_name(&declarator, text))
+                    .map(|name| name.to_lowercase())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let violations: Vec<Diagnostic> = group_names(node, text)
+            .into_iter()
+            .filter(|(name, _)| variables.contains(&name.to_lowercase()))
+            .map(|(name, range)| {
+                Diagnostic::new(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    range,
+                )
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["namelist_statement"]
+    }
+}
+
+/// Returns the group names in a `namelist` statement, along with their ranges.
+/// Group names are the only thing in the statement between slashes, as in
+/// `namelist /a/ x, y /b/ z`.
+fn group_names<'a>(node: &Node, src: &'a str) -> Vec<(&'a str, TextRange)> {
+    let Some(statement) = node.to_text(src) else {
+        return Vec::new();
+    };
+    let start = node.start_byte();
+    let slashes: Vec<usize> = statement.match_indices('/').map(|(i, _)| i).collect();
+    slashes
+        .chunks_exact(2)
+        .filter_map(|pair| {
+            let group = &statement[pair[0] + 1..pair[1]];
+            let name = group.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let offset = start + pair[0] + 1 + (group.len() - group.trim_start().len());
+            let start = TextSize::try_from(offset).unwrap();
+            let length = TextSize::try_from(name.len()).unwrap();
+            Some((name, TextRange::at(start, length)))
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+use crate::ast::{declarator_name, has_attribute, FortitudeNode};
+use crate::rules::correctness::procedure_pointers::is_pure;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `optional` dummy arguments of `pure` or `elemental` procedures
+/// that are used without being guarded by `present()`.
+///
+/// ## Why is this bad?
+/// Referencing an `optional` argument that wasn't passed is undefined
+/// behaviour, and typically crashes or reads garbage. In `pure` procedures,
+/// the compiler is free to reorder, combine, or skip calls, so the failure may
+/// show up somewhere else entirely, making it very hard to track down.
+///
+/// This rule counts a reference as guarded if it is inside an `if` whose
+/// condition calls `present()` on the argument, or comes after a check such as
+/// `if (.not. present(x)) return`. Passing the argument straight to another
+/// procedure is allowed, as the corresponding dummy argument may also be
+/// `optional`.
+///
+/// ## Example
+/// ```f90
+/// pure real function scale(x, factor)
+///   real, intent(in) :: x
+///   real, intent(in), optional :: factor
+///   scale = x * factor
+/// end function scale
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// pure real function scale(x, factor)
+///   real, intent(in) :: x
+///   real, intent(in), optional :: factor
+///   if (present(factor)) then
+///     scale = x * factor
+///   else
+///     scale = x
+///   end if
+/// end function scale
+/// ```
+#[violation]
+pub struct UnguardedOptionalInPure {
+    name: String,
+}
+
+impl Violation for UnguardedOptionalInPure {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!(
+            "optional argument '{name}' used in pure procedure without checking 'present({name})'"
+        )
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!("Guard the use with 'if (present({name}))'"))
+    }
+}
+
+/// Parts of a procedure that can't reference its arguments
+const SKIPPED_KINDS: &[&str] = &[
+    "subroutine_statement",
+    "function_statement",
+    "variable_declaration",
+    "internal_procedures",
+    "end_subroutine_statement",
+    "end_function_statement",
+];
+
+impl AstRule for UnguardedOptionalInPure {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = node.named_child(0)?;
+        if !is_pure(&statement, text) {
+            return None;
+        }
+
+        let parameters = statement.child_by_field_name("parameters")?;
+        let arguments: Vec<String> = parameters
+            .named_children(&mut parameters.walk())
+            .filter_map(|param| param.to_text(text))
+            .map(|param| param.to_lowercase())
+            .collect();
+        let optionals: Vec<String> = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .filter(|decl| has_attribute(decl, "optional", text))
+            .flat_map(|decl| {
+                decl.children_by_field_name("declarator", &mut decl.walk())
+                    .filter_map(|declarator| declarator_name(&declarator, text))
+                    .map(|name| name.to_lowercase())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|name| arguments.contains(name))
+            .collect();
+        if optionals.is_empty() {
+            return None;
+        }
+
+        // Conditions of all the `if` statements in the procedure, lowercase
+        // and without whitespace
+        let conditions: Vec<(Node, String)> = node
+            .named_descendants()
+            .filter(|child| matches!(child.kind(), "if_statement" | "elseif_clause"))
+            .filter_map(|statement| {
+                let line = statement.to_text(text)?.lines().next()?;
+                let condition: String = line
+                    .to_lowercase()
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                Some((statement, condition))
+            })
+            .collect();
+
+        let mut reported = Vec::new();
+        let violations = node
+            .named_children(&mut node.walk())
+            .filter(|child| !SKIPPED_KINDS.contains(&child.kind()))
+            .flat_map(|child| child.named_descendants().collect::<Vec<_>>())
+            .filter(|identifier| identifier.kind() == "identifier")
+            .filter_map(|identifier| {
+                let name = identifier.to_text(text)?;
+                let lowercase_name = name.to_lowercase();
+                if !optionals.contains(&lowercase_name) || reported.contains(&lowercase_name) {
+                    return None;
+                }
+                if matches!(
+                    identifier.parent()?.kind(),
+                    "argument_list" | "keyword_argument"
+                ) {
+                    return None;
+                }
+
+                let check = format!("present({lowercase_name})");
+                let negated_check = format!(".not.{check}");
+                let is_guarded = conditions.iter().any(|(statement, condition)| {
+                    (condition.contains(&check)
+                        && identifier
+                            .ancestors()
+                            .any(|ancestor| ancestor == *statement))
+                        || (condition.contains(&negated_check)
+                            && statement.end_byte() < identifier.start_byte())
+                });
+                if is_guarded {
+                    return None;
+                }
+
+                reported.push(lowercase_name);
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    &identifier,
+                ))
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine", "function"]
+    }
+}
@@ -0,0 +1,84 @@
+use crate::ast::{has_attribute, FortitudeNode};
+use crate::settings::{FortranStandard, Settings};
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `pointer` dummy arguments with an `intent` when targeting
+/// Fortran 95 or earlier, as set by `--target-std`.
+///
+/// ## Why is this bad?
+/// Before Fortran 2003, a `pointer` dummy argument could not have an `intent`
+/// attribute at all, and compilers for those standards will reject it. From
+/// Fortran 2003, the `intent` of a pointer refers to its association rather
+/// than to its target, so `intent(out)` means the pointer is undefined on
+/// entry, not that the data it points to will be overwritten.
+///
+/// ## Example
+/// ```f90
+/// subroutine make_array(array)
+///   real, pointer, intent(out) :: array(:)
+///   allocate(array(10))
+/// end subroutine make_array
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// subroutine make_array(array)
+///   real, pointer :: array(:)
+///   allocate(array(10))
+/// end subroutine make_array
+/// ```
+#[violation]
+pub struct PointerIntent {
+    intent: String,
+    target: FortranStandard,
+}
+
+impl Violation for PointerIntent {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { intent, target } = self;
+        format!("'{intent}' on a pointer requires Fortran 2003, but the target is {target}")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove the 'intent' attribute".to_string())
+    }
+}
+
+impl AstRule for PointerIntent {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.target_std >= FortranStandard::F2003 {
+            return None;
+        }
+
+        let text = src.source_text();
+        if !has_attribute(node, "pointer", text) {
+            return None;
+        }
+
+        let intent = node
+            .children_by_field_name("attribute", &mut node.walk())
+            .find(|attribute| {
+                attribute
+                    .to_text(text)
+                    .is_some_and(|attribute| attribute.to_lowercase().starts_with("intent"))
+            })?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                intent: intent.to_text(text)?.to_string(),
+                target: settings.target_std
+            },
+            &intent
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
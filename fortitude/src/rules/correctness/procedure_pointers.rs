@@ -0,0 +1,172 @@
+use crate::ast::{scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `pure` procedures associated with a procedure pointer whose
+/// interface is not `pure`.
+///
+/// ## Why is this bad?
+/// Calls through a procedure pointer only have the guarantees of the
+/// pointer's interface. If the interface isn't `pure`, then the pointer can't
+/// be called from `pure` procedures or `do concurrent` loops, even when it
+/// points to a `pure` procedure. This is often an oversight when writing the
+/// abstract interface. The opposite case, associating an impure procedure
+/// with a `pure` pointer, is rejected by the compiler.
+///
+/// This rule only checks interfaces and procedures defined in the same file.
+///
+/// ## Example
+/// ```f90
+/// abstract interface
+///   real function func(x)
+///     real, intent(in) :: x
+///   end function func
+/// end interface
+///
+/// procedure(func), pointer :: ptr
+/// ptr => square
+/// ...
+/// pure real function square(x)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// abstract interface
+///   pure real function func(x)
+///     real, intent(in) :: x
+///   end function func
+/// end interface
+/// ```
+#[violation]
+pub struct PureProcedureToImpurePointer {
+    procedure: String,
+    pointer: String,
+    interface: String,
+}
+
+impl Violation for PureProcedureToImpurePointer {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            procedure,
+            pointer,
+            interface,
+        } = self;
+        if interface.is_empty() {
+            format!(
+                "pure procedure '{procedure}' associated with '{pointer}', which has no interface"
+            )
+        } else {
+            format!("pure procedure '{procedure}' associated with '{pointer}', but interface '{interface}' is not pure")
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Make the pointer's interface pure".to_string())
+    }
+}
+
+impl AstRule for PureProcedureToImpurePointer {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let pointer = node.named_child(0)?;
+        let target = node.named_child(1)?;
+        if pointer.kind() != "identifier" || target.kind() != "identifier" {
+            return None;
+        }
+        let pointer_name = pointer.to_text(text)?;
+        let target_name = target.to_text(text)?;
+
+        let interface = pointer_interface(node, pointer_name, text)?;
+
+        let root = node.ancestors().last()?;
+        let procedures: Vec<(String, bool)> = root
+            .named_descendants()
+            .filter(|child| matches!(child.kind(), "subroutine_statement" | "function_statement"))
+            .filter_map(|statement| {
+                let name = statement.child_by_field_name("name")?.to_text(text)?;
+                Some((name.to_lowercase(), is_pure(&statement, text)))
+            })
+            .collect();
+        let is_pure_procedure = |name: &str| {
+            procedures
+                .iter()
+                .find(|(procedure, _)| procedure.eq_ignore_ascii_case(name))
+                .map(|(_, pure)| *pure)
+        };
+
+        if !is_pure_procedure(target_name)? {
+            return None;
+        }
+        // Unknown interfaces may come from another file, so we can't tell
+        if !interface.is_empty() && is_pure_procedure(&interface)? {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                procedure: target_name.to_string(),
+                pointer: pointer_name.to_string(),
+                interface,
+            },
+            &target
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["pointer_association_statement"]
+    }
+}
+
+/// The interface of the procedure pointer `name`, from a declaration like
+/// `procedure(interface), pointer :: name`, searching outwards from `node`
+/// through any host scopes. The interface is empty for `procedure()`.
+fn pointer_interface(node: &Node, name: &str, src: &str) -> Option<String> {
+    let mut scope = scoping_unit(node);
+    while let Some(unit) = scope {
+        let interface = unit
+            .named_children(&mut unit.walk())
+            .filter_map(|child| child.to_text(src))
+            .filter_map(|declaration| {
+                regex_captures!(
+                    r"^(?i)procedure\s*\(\s*(\w*)\s*\)([^:]*)::(.*)$",
+                    declaration
+                )
+            })
+            .find(|(_, _, attributes, names)| {
+                attributes.to_lowercase().contains("pointer")
+                    && names
+                        .split(',')
+                        .filter_map(|entity| entity.split("=>").next())
+                        .any(|entity| entity.trim().eq_ignore_ascii_case(name))
+            })
+            .map(|(_, interface, _, _)| interface.to_string());
+        if interface.is_some() {
+            return interface;
+        }
+        scope = scoping_unit(&unit);
+    }
+    None
+}
+
+/// Is the `subroutine_statement` or `function_statement` `pure`, either
+/// explicitly or through `elemental`?
+pub(crate) fn is_pure(statement: &Node, src: &str) -> bool {
+    let Some(text) = statement.to_text(src) else {
+        return false;
+    };
+    let Some((_, prefix)) = regex_captures!(r"^(?i)(.*?)\b(?:function|subroutine)\b", text) else {
+        return false;
+    };
+    let prefix = prefix.to_lowercase();
+    let words: Vec<&str> = prefix
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .collect();
+    words.contains(&"pure") || (words.contains(&"elemental") && !words.contains(&"impure"))
+}
@@ -0,0 +1,134 @@
+use crate::ast::{declarator_name, find_variable_declaration, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for assignments to `protected` module variables outside of the
+/// module that defines them.
+///
+/// ## Why is this bad?
+/// The `protected` attribute makes a module variable read-only everywhere
+/// except inside its own module. Assigning to it through `use` association is
+/// not allowed by the standard, and not every compiler diagnoses it.
+///
+/// This rule can currently only see modules defined in the file being checked.
+///
+/// ## Example
+/// ```f90
+/// module config
+///   implicit none (type, external)
+///   real, protected :: tolerance = 1.0e-6
+/// end module config
+///
+/// subroutine tighten()
+///   use config, only: tolerance
+///   implicit none (type, external)
+///   tolerance = 1.0e-9  ! Not allowed
+/// end subroutine tighten
+/// ```
+#[violation]
+pub struct ProtectedVariableModified {
+    name: String,
+    module: String,
+}
+
+impl Violation for ProtectedVariableModified {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, module } = self;
+        format!("'{name}' is protected in module '{module}' and cannot be modified here")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { module, .. } = self;
+        Some(format!("Modify it through a procedure in '{module}'"))
+    }
+}
+
+impl AstRule for ProtectedVariableModified {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        // Find the variable at the root of targets like `x(1)` or `x%y`
+        let mut target = node.child_by_field_name("left")?;
+        while target.kind() != "identifier" {
+            target = target.named_child(0)?;
+        }
+        let name = target.to_text(text)?;
+        // A local declaration hides anything use associated
+        if find_variable_declaration(node, name, text).is_some() {
+            return None;
+        }
+
+        let root = node.ancestors().last()?;
+        let mut scope = scoping_unit(node);
+        while let Some(unit) = scope {
+            let module = unit
+                .named_children(&mut unit.walk())
+                .filter(|child| child.kind() == "use_statement")
+                .filter(|statement| imports(statement, name, text))
+                .filter_map(|statement| statement.child_with_name("module_name")?.to_text(text))
+                .find(|module| declares_protected(&root, module, name, text));
+            if let Some(module) = module {
+                return some_vec!(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                        module: module.to_string(),
+                    },
+                    &target
+                ));
+            }
+            scope = scoping_unit(&unit);
+        }
+        None
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["assignment_statement"]
+    }
+}
+
+/// Could `use_statement` make `name` available? True for plain `use` statements,
+/// or if `name` appears in the `only` list.
+fn imports(use_statement: &Node, name: &str, src: &str) -> bool {
+    let Some(items) = use_statement.child_with_name("included_items") else {
+        return true;
+    };
+    items
+        .named_descendants()
+        .filter(|item| item.kind() == "identifier")
+        .filter_map(|item| item.to_text(src))
+        .any(|item| item.eq_ignore_ascii_case(name))
+}
+
+/// Does a module called `module` in this file declare `name` as `protected`?
+fn declares_protected(root: &Node, module: &str, name: &str, src: &str) -> bool {
+    root.named_children(&mut root.walk())
+        .filter(|child| child.kind() == "module")
+        .filter(|child| {
+            child
+                .child_with_name("module_statement")
+                .and_then(|statement| statement.child_with_name("name"))
+                .and_then(|module_name| module_name.to_text(src))
+                .is_some_and(|module_name| module_name.eq_ignore_ascii_case(module))
+        })
+        .flat_map(|child| {
+            child
+                .named_children(&mut child.walk())
+                .filter(|decl| decl.kind() == "variable_declaration")
+                .collect::<Vec<_>>()
+        })
+        .filter(|decl| {
+            decl.children_by_field_name("attribute", &mut decl.walk())
+                .filter_map(|attribute| attribute.to_text(src))
+                .any(|attribute| attribute.eq_ignore_ascii_case("protected"))
+        })
+        .any(|decl| {
+            decl.children_by_field_name("declarator", &mut decl.walk())
+                .filter_map(|declarator| declarator_name(&declarator, src))
+                .any(|declared| declared.eq_ignore_ascii_case(name))
+        })
+}
@@ -0,0 +1,72 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for calls to `random_number` in files that never call `random_seed`.
+///
+/// ## Why is this bad?
+/// Without `random_seed`, the initial state of the random number generator
+/// depends on the compiler: some use the same seed for every run, and others
+/// a different one. Either way, the sequence can't be controlled, so results
+/// can't be reproduced in tests or compared between compilers. Calling
+/// `random_seed` makes the choice explicit.
+///
+/// To avoid false positives, this rule doesn't fire if `random_seed` is called
+/// anywhere in the same file.
+///
+/// ## Example
+/// ```f90
+/// real :: x
+/// call random_number(x)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real :: x
+/// call random_seed(put=seed)
+/// call random_number(x)
+/// ```
+#[violation]
+pub struct RandomNumberWithoutSeed {}
+
+impl Violation for RandomNumberWithoutSeed {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'random_number' called without initialising the seed with 'random_seed'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Call 'random_seed' before 'random_number'".to_string())
+    }
+}
+
+impl AstRule for RandomNumberWithoutSeed {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let name = node.named_child(0)?;
+        if !name.to_text(text)?.eq_ignore_ascii_case("random_number") {
+            return None;
+        }
+
+        let root = node.ancestors().last()?;
+        let has_seed = root
+            .named_descendants()
+            .filter(|child| child.kind() == "subroutine_call")
+            .filter_map(|call| call.named_child(0)?.to_text(text))
+            .any(|callee| callee.eq_ignore_ascii_case("random_seed"));
+        if has_seed {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(Self {}, node))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine_call"]
+    }
+}
@@ -0,0 +1,106 @@
+use crate::ast::{is_keyword_argument, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `write` statements to a unit that was opened with
+/// `action='read'` in the same scope.
+///
+/// ## Why is this bad?
+/// Writing to a file opened for reading only fails at runtime. This is
+/// usually caused by reusing a unit number, or by copying an `open` statement
+/// without updating its `action`.
+///
+/// This rule only matches units by their text, so `open(10, ...)` and
+/// `write(10, ...)`, or `open(newunit=u, ...)` and `write(u, ...)`, are
+/// compared, but units that are changed between the two statements aren't
+/// tracked.
+///
+/// ## Example
+/// ```f90
+/// open (10, file="input.dat", action="read")
+/// write (10, *) result
+/// ```
+#[violation]
+pub struct WriteToReadOnlyUnit {
+    unit: String,
+}
+
+impl Violation for WriteToReadOnlyUnit {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { unit } = self;
+        format!("'write' to unit '{unit}', which was opened with action='read'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Open the unit with action='write' or action='readwrite'".to_string())
+    }
+}
+
+impl AstRule for WriteToReadOnlyUnit {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let unit = io_unit(node, text)?;
+        let unit_text = unit.to_text(text)?;
+
+        let is_read_only = open_specifier(node, unit_text, "action", text)
+            .is_some_and(|action| action.eq_ignore_ascii_case("read"));
+        if !is_read_only {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                unit: unit_text.to_string()
+            },
+            &unit
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["write_statement"]
+    }
+}
+
+/// The unit of an IO statement, given either positionally or as `unit=` or `newunit=`
+pub(crate) fn io_unit<'a>(node: &Node<'a>, src: &str) -> Option<Node<'a>> {
+    if let Some(unit) = node.child_with_name("unit_identifier") {
+        return unit.child(0);
+    }
+    node.named_children(&mut node.walk())
+        .find(|child| {
+            is_keyword_argument(child, "unit", src) || is_keyword_argument(child, "newunit", src)
+        })?
+        .child_by_field_name("value")
+}
+
+/// The value of the `specifier` argument, without quotes, of the last `open`
+/// statement for `unit` before `node` in the same scope
+pub(crate) fn open_specifier<'a>(
+    node: &Node,
+    unit: &str,
+    specifier: &str,
+    src: &'a str,
+) -> Option<&'a str> {
+    let open = scoping_unit(node)?
+        .named_descendants()
+        .filter(|child| child.kind() == "open_statement")
+        .filter(|open| open.start_byte() < node.start_byte())
+        .filter(|open| {
+            io_unit(open, src)
+                .and_then(|open_unit| open_unit.to_text(src))
+                .is_some_and(|open_unit| open_unit.eq_ignore_ascii_case(unit))
+        })
+        .last()?;
+    let value = open
+        .named_children(&mut open.walk())
+        .find(|child| is_keyword_argument(child, specifier, src))?
+        .child_by_field_name("value")?
+        .to_text(src)?;
+    Some(value.trim_matches(|c| c == '"' || c == '\''))
+}
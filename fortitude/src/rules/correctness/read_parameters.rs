@@ -0,0 +1,72 @@
+use crate::ast::{find_variable_declaration, has_attribute, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `read` statements that read into a `parameter`.
+///
+/// ## Why is this bad?
+/// Named constants are fixed at compile time, and can't be assigned to at run
+/// time. Most compilers will reject this, but some accept it and either ignore
+/// the value read or overwrite what should be read-only memory.
+///
+/// ## Example
+/// ```f90
+/// integer, parameter :: n = 10
+/// read (*, *) n
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// integer :: n
+/// read (*, *) n
+/// ```
+#[violation]
+pub struct ReadIntoParameter {
+    name: String,
+}
+
+impl Violation for ReadIntoParameter {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'{name}' is a parameter and can't be read into")
+    }
+}
+
+impl AstRule for ReadIntoParameter {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let items = node.child_with_name("input_item_list")?;
+        let violations = items
+            .named_children(&mut items.walk())
+            .filter_map(|item| {
+                // Find the variable at the root of items like `x(1)` or `x%y`
+                let mut target = item;
+                while target.kind() != "identifier" {
+                    target = target.named_child(0)?;
+                }
+                let name = target.to_text(text)?;
+                let declaration = find_variable_declaration(node, name, text)?;
+                if !has_attribute(&declaration, "parameter", text) {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    &target,
+                ))
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["read_statement"]
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ast::FortitudeNode;
+use crate::rules::typing::literal_kinds::iso_fortran_env_param;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for real literals that are outside the range of their type, such as
+/// `1.0e400`.
+///
+/// ## Why is this bad?
+/// Literals with an `e` exponent are default `real`, normally IEEE 754 single
+/// precision, which only covers magnitudes from around `1e-45` to `3.4e38`.
+/// Literals with a `d` exponent are double precision, which covers around
+/// `5e-324` to `1.8e308`. Values beyond these limits overflow to infinity or
+/// underflow to zero, or are rejected by the compiler.
+///
+/// A common mistake is writing a large value with an `e` exponent when it
+/// was meant to be double precision: use a `d` exponent, or better, a kind
+/// suffix such as `1.0e200_real64`. This means `1.0e200` is reported even
+/// when it is assigned to a double precision variable, as the literal itself
+/// is still single precision.
+///
+/// Literals with a `real32` or `real64` kind suffix, or the equivalent number
+/// such as `_8`, are checked against the limits of that kind. Other kind
+/// suffixes are not checked, as their precision isn't known.
+///
+/// ## Example
+/// ```f90
+/// huge_value = 1.0e200
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// huge_value = 1.0e200_real64
+/// ```
+#[violation]
+pub struct RealLiteralOutOfRange {
+    literal: String,
+    precision: &'static str,
+    overflow: bool,
+}
+
+impl Violation for RealLiteralOutOfRange {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            literal,
+            precision,
+            overflow,
+        } = self;
+        if *overflow {
+            format!("'{literal}' is too large for {precision}")
+        } else {
+            format!("'{literal}' is too small for {precision}, and will be zero")
+        }
+    }
+}
+
+impl AstRule for RealLiteralOutOfRange {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let literal = node.to_text(src.source_text())?;
+        let (_, mantissa, letter, exponent, kind) =
+            regex_captures!(r"^(\d+\.?\d*|\.\d+)([eEdD])([+-]?\d+)(?:_(\w+))?$", literal)?;
+
+        let mantissa: f64 = mantissa.parse().ok()?;
+        if mantissa == 0.0 {
+            return None;
+        }
+        let exponent: f64 = exponent.parse().ok()?;
+        let magnitude = mantissa.log10() + exponent;
+
+        let is_double = if kind.is_empty() {
+            letter.eq_ignore_ascii_case("d")
+        } else {
+            let kind = match kind.parse::<u8>() {
+                Ok(kind) => iso_fortran_env_param("real", kind)?,
+                Err(_) => kind.to_lowercase(),
+            };
+            match kind.as_str() {
+                "real32" => false,
+                "real64" => true,
+                _ => return None,
+            }
+        };
+        let (precision, max, min) = if is_double {
+            ("double precision", f64::MAX.log10(), -323.3)
+        } else {
+            ("single precision", f32::MAX.log10() as f64, -44.85)
+        };
+        if min <= magnitude && magnitude <= max {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                literal: literal.to_string(),
+                precision,
+                overflow: magnitude > max,
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["number_literal"]
+    }
+}
@@ -0,0 +1,86 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for real literals with nothing after the decimal point, such as `1.`,
+/// and optionally for those with nothing before it, such as `.5`.
+///
+/// ## Why is this bad?
+/// A trailing decimal point is easy to miss, and `1.` can be mistaken for the
+/// integer `1`, or for the start of an operator such as `1.eq.x`. Writing the
+/// zero explicitly makes it clear that the literal is real.
+///
+/// Literals with no digits before the decimal point are only reported if
+/// `--require-real-leading-zero` is set.
+///
+/// ## Example
+/// ```f90
+/// x = 1.
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// x = 1.0
+/// ```
+#[violation]
+pub struct IncompleteRealLiteral {
+    literal: String,
+    replacement: String,
+}
+
+impl AlwaysFixableViolation for IncompleteRealLiteral {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            literal,
+            replacement,
+        } = self;
+        format!("incomplete real literal '{literal}', prefer '{replacement}'")
+    }
+
+    fn fix_title(&self) -> String {
+        let Self { replacement, .. } = self;
+        format!("Replace with '{replacement}'")
+    }
+}
+
+impl AstRule for IncompleteRealLiteral {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let literal = node.to_text(src.source_text())?;
+        let (_, whole, fraction) =
+            regex_captures!(r"^(\d*)\.(\d*)(?:[eEdD][+-]?\d+)?(?:_\w+)?$", literal)?;
+        // Exponent and kind suffix
+        let rest = &literal[whole.len() + 1 + fraction.len()..];
+
+        let whole = if whole.is_empty() && settings.require_real_leading_zero {
+            "0"
+        } else {
+            whole
+        };
+        let fraction = if fraction.is_empty() { "0" } else { fraction };
+        let replacement = format!("{whole}.{fraction}{rest}");
+        if replacement == literal {
+            return None;
+        }
+
+        let fix = Fix::safe_edit(node.edit_replacement(src, replacement.clone()));
+        some_vec!(Diagnostic::from_node(
+            Self {
+                literal: literal.to_string(),
+                replacement
+            },
+            node
+        )
+        .with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["number_literal"]
+    }
+}
@@ -0,0 +1,109 @@
+use crate::ast::{declares_array, find_variable_declaration, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::{regex_captures, regex_is_match};
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for real literals used as array subscripts or `do` loop bounds.
+///
+/// ## Why is this bad?
+/// Array subscripts and loop bounds should be integers. Some compilers will
+/// silently convert a real value such as `arr(2.0)` to an integer, while others
+/// reject it, and real loop bounds are a deleted feature of the language. Even
+/// where it works, a real value here hides the intent and may truncate
+/// unexpectedly, for example `arr(2.9)` refers to `arr(2)`.
+///
+/// This rule can only tell array indexing apart from function calls for arrays
+/// declared in the current or an enclosing scope.
+///
+/// ## Example
+/// ```f90
+/// real :: arr(10)
+/// x = arr(2.0)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real :: arr(10)
+/// x = arr(2)
+/// ```
+#[violation]
+pub struct RealLiteralWhereIntegerExpected {
+    literal: String,
+    replacement: Option<String>,
+}
+
+impl Violation for RealLiteralWhereIntegerExpected {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { literal, .. } = self;
+        format!("real literal '{literal}' used where an integer is expected")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { replacement, .. } = self;
+        replacement
+            .as_ref()
+            .map(|replacement| format!("Replace with '{replacement}'"))
+    }
+}
+
+impl AstRule for RealLiteralWhereIntegerExpected {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let candidates: Vec<Node> = match node.kind() {
+            "call_expression" => {
+                let name = node.child_with_name("identifier")?.to_text(text)?;
+                let declaration = find_variable_declaration(node, name, text)?;
+                if !declares_array(&declaration, name, text) {
+                    return None;
+                }
+                let arguments = node.child_with_name("argument_list")?;
+                arguments.named_children(&mut arguments.walk()).collect()
+            }
+            // Skip the loop variable itself
+            _ => node.named_children(&mut node.walk()).skip(1).collect(),
+        };
+
+        let violations = candidates
+            .iter()
+            .filter(|candidate| candidate.kind() == "number_literal")
+            .filter_map(|literal| {
+                let literal_text = literal.to_text(text)?;
+                if !regex_is_match!(
+                    r"^((\d+\.\d*|\d*\.\d+)([eEdD][+-]?\d+)?|\d+[eEdD][+-]?\d+)(_\w+)?$",
+                    literal_text
+                ) {
+                    return None;
+                }
+                // Only offer a fix if the value is a whole number
+                let replacement = regex_captures!(r"^(\d+)\.0*$", literal_text)
+                    .map(|(_, whole)| whole.to_string());
+                let diagnostic = Diagnostic::from_node(
+                    Self {
+                        literal: literal_text.to_string(),
+                        replacement: replacement.clone(),
+                    },
+                    literal,
+                );
+                Some(match replacement {
+                    Some(replacement) => diagnostic
+                        .with_fix(Fix::safe_edit(literal.edit_replacement(src, replacement))),
+                    None => diagnostic,
+                })
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["call_expression", "loop_control_expression"]
+    }
+}
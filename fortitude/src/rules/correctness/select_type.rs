@@ -0,0 +1,121 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::{regex, regex_captures};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `type is` branches in `select type` constructs for derived
+/// types that are extended elsewhere in the same file.
+///
+/// ## Why is this bad?
+/// `type is (T)` only matches when the dynamic type is exactly `T`, not any
+/// type that extends it. When `T` is the parent of other types, this is easy to
+/// overlook: objects of the extended types silently fall through to another
+/// branch, or to no branch at all. If extensions of `T` should be handled the
+/// same way, use `class is (T)` instead.
+///
+/// Branches are not reported if the same `select type` also has a
+/// `class is (T)` branch, as then the distinction is clearly intended.
+///
+/// ## Example
+/// ```f90
+/// type :: shape
+/// end type shape
+/// type, extends(shape) :: circle
+/// end type circle
+/// ...
+/// select type (s)
+/// type is (shape)
+///   call draw_outline(s)
+/// end select
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// select type (s)
+/// class is (shape)
+///   call draw_outline(s)
+/// end select
+/// ```
+#[violation]
+pub struct TypeIsWithExtensions {
+    name: String,
+}
+
+impl Violation for TypeIsWithExtensions {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'type is ({name})' does not match types extending '{name}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!(
+            "Use 'class is ({name})' if extensions should also match"
+        ))
+    }
+}
+
+impl AstRule for TypeIsWithExtensions {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let root = node.ancestors().last()?;
+        let extended: Vec<String> = root
+            .named_descendants()
+            .filter(|child| child.kind() == "derived_type_statement")
+            .filter_map(|statement| {
+                let (_, parent) =
+                    regex_captures!(r"(?i)extends\s*\(\s*(\w+)\s*\)", statement.to_text(text)?)?;
+                Some(parent.to_lowercase())
+            })
+            .collect();
+        if extended.is_empty() {
+            return None;
+        }
+
+        let guards: Vec<_> = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "type_statement")
+            .filter_map(|statement| {
+                let captures = regex!(r"^(?i)(type|class)\s+is\s*\(\s*(\w+)\s*\)")
+                    .captures(statement.to_text(text)?)?;
+                let kind = captures.get(1)?.as_str().to_lowercase();
+                let name = captures.get(2)?;
+                Some((statement, kind, name.as_str(), name.start()))
+            })
+            .collect();
+
+        let violations = guards
+            .iter()
+            .filter(|(_, kind, name, _)| {
+                let lowercase_name = name.to_lowercase();
+                kind == "type"
+                    && extended.contains(&lowercase_name)
+                    && !guards.iter().any(|(_, other_kind, other_name, _)| {
+                        other_kind == "class" && other_name.eq_ignore_ascii_case(name)
+                    })
+            })
+            .map(|(statement, _, name, offset)| {
+                let start = TextSize::try_from(statement.start_byte() + offset).unwrap();
+                let length = TextSize::try_from(name.len()).unwrap();
+                Diagnostic::new(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    TextRange::at(start, length),
+                )
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["select_type_statement"]
+    }
+}
@@ -0,0 +1,142 @@
+use crate::ast::{declarator_name, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::{regex_captures, regex_is_match};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for dummy arguments of `bind(c)` procedures that are declared with a
+/// `sequence` type which isn't also `bind(c)`.
+///
+/// ## Why is this bad?
+/// `sequence` only guarantees that the components are stored in the order
+/// they're declared, so that the same type can be declared in more than one
+/// place. It says nothing about padding or alignment, so the layout may not
+/// match the equivalent C `struct`. Only `bind(c)` types are interoperable with
+/// C.
+///
+/// This rule only checks types defined in the same file as the procedure.
+///
+/// ## Example
+/// ```f90
+/// type :: point
+///   sequence
+///   real :: x, y
+/// end type point
+///
+/// interface
+///   subroutine draw(p) bind(c)
+///     import :: point
+///     type(point), intent(in) :: p
+///   end subroutine draw
+/// end interface
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// type, bind(c) :: point
+///   real(c_float) :: x, y
+/// end type point
+/// ```
+#[violation]
+pub struct SequenceTypeInBindC {
+    name: String,
+}
+
+impl Violation for SequenceTypeInBindC {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'sequence' type '{name}' passed to a 'bind(c)' procedure")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!("Declare '{name}' with 'bind(c)' instead"))
+    }
+}
+
+impl AstRule for SequenceTypeInBindC {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statement = node.named_child(0).filter(|child| {
+            matches!(child.kind(), "subroutine_statement" | "function_statement")
+        })?;
+        if !regex_is_match!(r"(?i)\bbind\s*\(\s*c\b", statement.to_text(text)?) {
+            return None;
+        }
+
+        let parameters = statement.child_by_field_name("parameters")?;
+        let arguments: Vec<String> = parameters
+            .named_children(&mut parameters.walk())
+            .filter_map(|param| param.to_text(text))
+            .map(|param| param.to_lowercase())
+            .collect();
+        if arguments.is_empty() {
+            return None;
+        }
+
+        let root = node.ancestors().last()?;
+        let sequence_types = sequence_types(&root, text);
+        if sequence_types.is_empty() {
+            return None;
+        }
+
+        let violations = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .filter(|decl| {
+                decl.children_by_field_name("declarator", &mut decl.walk())
+                    .filter_map(|declarator| declarator_name(&declarator, text))
+                    .any(|name| arguments.contains(&name.to_lowercase()))
+            })
+            .filter_map(|decl| {
+                let type_ = decl.child_by_field_name("type")?;
+                let (_, name) =
+                    regex_captures!(r"^(?i)type\s*\(\s*(\w+)\s*\)", type_.to_text(text)?)?;
+                if !sequence_types.contains(&name.to_lowercase()) {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    &type_,
+                ))
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine", "function"]
+    }
+}
+
+/// Lowercase names of the derived types in the file that have `sequence`
+/// without `bind(c)`
+fn sequence_types(root: &Node, src: &str) -> Vec<String> {
+    root.named_descendants()
+        .filter(|child| child.kind() == "derived_type_definition")
+        .filter_map(|definition| {
+            let statement = definition.child_with_name("derived_type_statement")?;
+            let statement_text = statement.to_text(src)?;
+            if regex_is_match!(r"(?i)\bbind\s*\(\s*c\b", statement_text) {
+                return None;
+            }
+            let has_sequence = regex_is_match!(r"(?i)^type\s*,.*\bsequence\b", statement_text)
+                || definition
+                    .named_children(&mut definition.walk())
+                    .filter_map(|child| child.to_text(src))
+                    .any(|child| child.trim().eq_ignore_ascii_case("sequence"));
+            if !has_sequence {
+                return None;
+            }
+            let name = statement.child_with_name("type_name")?.to_text(src)?;
+            Some(name.to_lowercase())
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+use crate::ast::{find_variable_declaration, has_attribute, is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for calls to `allocated` with a variable that isn't declared
+/// `allocatable`.
+///
+/// ## Why is this bad?
+/// `allocated` reports the allocation status of an allocatable variable, and
+/// is not defined for any other variables. In particular, it can't be used to
+/// check whether a pointer has been allocated: use `associated` for that.
+///
+/// This rule only considers variables declared in the current or an enclosing
+/// scope.
+///
+/// ## Example
+/// ```f90
+/// real, pointer :: x(:)
+/// if (allocated(x)) deallocate(x)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real, pointer :: x(:)
+/// if (associated(x)) deallocate(x)
+/// ```
+#[violation]
+pub struct AllocatedNonAllocatable {
+    name: String,
+}
+
+impl Violation for AllocatedNonAllocatable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'allocated' called on '{name}', which is not allocatable")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!(
+            "Declare '{name}' as 'allocatable', or use 'associated' for pointers"
+        ))
+    }
+}
+
+impl AstRule for AllocatedNonAllocatable {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let (argument, declaration) =
+            inquiry_argument(node, "allocated", &["array", "scalar"], text)?;
+        if has_attribute(&declaration, "allocatable", text) {
+            return None;
+        }
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: argument.to_text(text)?.to_string()
+            },
+            &argument
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["call_expression"]
+    }
+}
+
+/// ## What it does
+/// Checks for calls to `associated` with a variable that isn't declared as a
+/// `pointer`.
+///
+/// ## Why is this bad?
+/// `associated` reports the association status of a pointer, and its first
+/// argument must be a pointer. Calling it on any other variable is an error.
+/// To check whether an allocatable variable has been allocated, use
+/// `allocated` instead.
+///
+/// This rule only considers variables declared in the current or an enclosing
+/// scope.
+///
+/// ## Example
+/// ```f90
+/// real, allocatable :: x(:)
+/// if (associated(x)) deallocate(x)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real, allocatable :: x(:)
+/// if (allocated(x)) deallocate(x)
+/// ```
+#[violation]
+pub struct AssociatedNonPointer {
+    name: String,
+}
+
+impl Violation for AssociatedNonPointer {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'associated' called on '{name}', which is not a pointer")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!(
+            "Declare '{name}' as a 'pointer', or use 'allocated' for allocatables"
+        ))
+    }
+}
+
+impl AstRule for AssociatedNonPointer {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let (argument, declaration) = inquiry_argument(node, "associated", &["pointer"], text)?;
+        if has_attribute(&declaration, "pointer", text) {
+            return None;
+        }
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: argument.to_text(text)?.to_string()
+            },
+            &argument
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["call_expression"]
+    }
+}
+
+/// If `node` is a call to the intrinsic `intrinsic`, returns its first
+/// argument, given either positionally or as one of `keywords`, along with the
+/// argument's declaration. Only plain variables are considered.
+fn inquiry_argument<'a>(
+    node: &Node<'a>,
+    intrinsic: &str,
+    keywords: &[&str],
+    src: &str,
+) -> Option<(Node<'a>, Node<'a>)> {
+    let name = node.child_with_name("identifier")?.to_text(src)?;
+    if !name.eq_ignore_ascii_case(intrinsic) {
+        return None;
+    }
+    // Skip variables that shadow the intrinsic
+    if find_variable_declaration(node, name, src).is_some() {
+        return None;
+    }
+
+    let argument_list = node.child_with_name("argument_list")?;
+    let first = argument_list
+        .named_children(&mut argument_list.walk())
+        .find(|child| child.kind() != "comment")?;
+    let argument = if first.kind() == "keyword_argument" {
+        if !keywords
+            .iter()
+            .any(|keyword| is_keyword_argument(&first, keyword, src))
+        {
+            return None;
+        }
+        first.child_by_field_name("value")?
+    } else {
+        first
+    };
+    if argument.kind() != "identifier" {
+        return None;
+    }
+
+    let declaration = find_variable_declaration(node, argument.to_text(src)?, src)?;
+    Some((argument, declaration))
+}
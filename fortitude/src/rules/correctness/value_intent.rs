@@ -0,0 +1,80 @@
+use crate::ast::{has_attribute, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for dummy arguments with both the `value` attribute and
+/// `intent(out)` or `intent(inout)`.
+///
+/// ## Why is this bad?
+/// A `value` argument is a local copy of the actual argument, so any changes
+/// made to it are never seen by the caller. An `intent(out)` or
+/// `intent(inout)` says the opposite, and the standard forbids combining them.
+/// Either drop `value` so that changes are passed back, or change the intent
+/// to `intent(in)`, or remove it, if the argument is only modified locally.
+///
+/// ## Example
+/// ```f90
+/// subroutine increment(x)
+///   integer, value, intent(inout) :: x
+///   x = x + 1
+/// end subroutine increment
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// subroutine increment(x)
+///   integer, intent(inout) :: x
+///   x = x + 1
+/// end subroutine increment
+/// ```
+#[violation]
+pub struct ValueWithOutputIntent {
+    intent: String,
+}
+
+impl Violation for ValueWithOutputIntent {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { intent } = self;
+        format!("'value' argument declared with '{intent}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { intent } = self;
+        Some(format!("Remove either 'value' or '{intent}'"))
+    }
+}
+
+impl AstRule for ValueWithOutputIntent {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if !has_attribute(node, "value", text) {
+            return None;
+        }
+
+        let intent = node
+            .children_by_field_name("attribute", &mut node.walk())
+            .find(|attribute| {
+                attribute.to_text(text).is_some_and(|attribute| {
+                    regex_is_match!(r"^(?i)intent\s*\(\s*(in\s*out|out)\s*\)$", attribute)
+                })
+            })?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                intent: intent.to_text(text)?.to_string(),
+            },
+            &intent
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
@@ -0,0 +1,154 @@
+use crate::ast::{declarator_name, has_attribute, scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for variables declared `volatile` with no apparent need.
+///
+/// ## Why is this bad?
+/// The `volatile` attribute tells the compiler that a variable may change
+/// outside of the normal flow of the program, so every access has to go to
+/// memory. This prevents many optimisations, and should be reserved for
+/// variables that really are modified elsewhere, such as those shared between
+/// threads, updated by signal handlers, or mapped to hardware.
+///
+/// This rule skips variables that are:
+/// - in a procedure or program containing an OpenMP `parallel` directive;
+/// - passed to a `bind(c)` procedure;
+/// - explained by a comment mentioning `volatile` at the end of the same line,
+///   or on its own on the preceding line;
+/// - matched by one of the patterns given by `--allowed-volatile-names`.
+///
+/// ## Example
+/// ```f90
+/// subroutine plain()
+///   real, volatile :: x
+///   x = 1.0
+/// end subroutine plain
+/// ```
+#[violation]
+pub struct UnjustifiedVolatile {
+    name: String,
+}
+
+impl Violation for UnjustifiedVolatile {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'{name}' is declared 'volatile' without an apparent need")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove 'volatile', or add a comment explaining why it is needed".to_string())
+    }
+}
+
+impl AstRule for UnjustifiedVolatile {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        if !has_attribute(node, "volatile", text) {
+            return None;
+        }
+
+        let scope = scoping_unit(node)?;
+        let comments: Vec<Node> = scope
+            .named_descendants()
+            .filter(|child| child.kind() == "comment")
+            .collect();
+
+        // Shared between OpenMP threads
+        if comments.iter().any(|comment| {
+            comment
+                .to_text(text)
+                .is_some_and(|comment| regex_is_match!(r"(?i)^!\$omp\s+parallel", comment))
+        }) {
+            return None;
+        }
+
+        // Justified by a trailing comment, or a comment on its own on the line above
+        let line = node.start_position().row;
+        if comments.iter().any(|comment| {
+            let comment_line = comment.start_position().row;
+            let own_line = text[..comment.start_byte()]
+                .rsplit('\n')
+                .next()
+                .is_some_and(|indent| indent.trim().is_empty());
+            (comment_line == line || (comment_line + 1 == line && own_line))
+                && comment
+                    .to_text(text)
+                    .is_some_and(|comment| comment.to_lowercase().contains("volatile"))
+        }) {
+            return None;
+        }
+
+        let bind_c_procedures = bind_c_procedures(node, text);
+        let violations = node
+            .children_by_field_name("declarator", &mut node.walk())
+            .filter_map(|declarator| {
+                let name = declarator_name(&declarator, text)?;
+                if settings.allowed_volatile_names.matches(name)
+                    || passed_to_any(&scope, name, &bind_c_procedures, text)
+                {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    &declarator,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
+
+/// Lowercase names of all procedures in the file declared with `bind(c)`
+fn bind_c_procedures(node: &Node, src: &str) -> Vec<String> {
+    let Some(root) = node.ancestors().last() else {
+        return vec![];
+    };
+    root.named_descendants()
+        .filter(|child| matches!(child.kind(), "subroutine_statement" | "function_statement"))
+        .filter(|statement| {
+            statement
+                .to_text(src)
+                .is_some_and(|statement| regex_is_match!(r"(?i)\bbind\s*\(\s*c\b", statement))
+        })
+        .filter_map(|statement| statement.child_by_field_name("name"))
+        .filter_map(|name| name.to_text(src))
+        .map(|name| name.to_lowercase())
+        .collect()
+}
+
+/// Is `name` passed as an argument to one of `procedures` within `scope`?
+fn passed_to_any(scope: &Node, name: &str, procedures: &[String], src: &str) -> bool {
+    if procedures.is_empty() {
+        return false;
+    }
+    scope
+        .named_descendants()
+        .filter(|child| matches!(child.kind(), "subroutine_call" | "call_expression"))
+        .filter(|call| {
+            call.named_child(0)
+                .and_then(|callee| callee.to_text(src))
+                .is_some_and(|callee| procedures.contains(&callee.to_lowercase()))
+        })
+        .filter_map(|call| call.child_with_name("argument_list"))
+        .any(|arguments| {
+            arguments
+                .named_children(&mut arguments.walk())
+                .filter_map(|argument| argument.to_text(src))
+                .any(|argument| argument.eq_ignore_ascii_case(name))
+        })
+}
@@ -0,0 +1,178 @@
+use crate::ast::{declarator_name, find_variable_declaration, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `character` declarations with a length of zero.
+///
+/// ## Why is this bad?
+/// A zero-length string is allowed, but can't hold any characters, so any
+/// assignment to it is silently truncated to nothing. This is almost always
+/// a mistake, such as a length parameter that was set to the wrong value.
+///
+/// Lengths given as a literal `0`, or as a named constant declared in the
+/// current or an enclosing scope with the value `0`, are reported.
+///
+/// ## Example
+/// ```f90
+/// character(len=0) :: name
+/// ```
+#[violation]
+pub struct ZeroLengthCharacter {
+    length: String,
+}
+
+impl Violation for ZeroLengthCharacter {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { length } = self;
+        if length == "0" {
+            format!("'character' declared with length 0")
+        } else {
+            format!("'character' declared with length '{length}', which is 0")
+        }
+    }
+}
+
+impl AstRule for ZeroLengthCharacter {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let length = character_length(node, text)?;
+        let length_text = length.to_text(text)?;
+        let is_zero = match length.kind() {
+            "number_literal" => is_zero_literal(length_text),
+            "identifier" => is_zero_parameter(&length, length_text, text),
+            _ => false,
+        };
+        if !is_zero {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                length: length_text.to_string()
+            },
+            &length
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["intrinsic_type"]
+    }
+}
+
+/// ## What it does
+/// Checks for `character` declarations with a negative length.
+///
+/// ## Why is this bad?
+/// A negative length is treated as zero, giving a string that can't hold any
+/// characters. Some compilers warn about this, but it isn't an error, so the
+/// mistake can easily go unnoticed. This is usually a typo, or a length
+/// calculated the wrong way round.
+///
+/// ## Example
+/// ```f90
+/// character(len=-1) :: name
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// character(len=1) :: name
+/// ```
+#[violation]
+pub struct NegativeCharacterLength {
+    length: String,
+}
+
+impl Violation for NegativeCharacterLength {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { length } = self;
+        format!("'character' declared with negative length '{length}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use a positive length".to_string())
+    }
+}
+
+impl AstRule for NegativeCharacterLength {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let length = character_length(node, text)?;
+        let length_text = length.to_text(text)?;
+        if length.kind() != "unary_expression" || !regex_is_match!(r"^-\s*\d+(_\w+)?$", length_text)
+        {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                length: length_text.to_string()
+            },
+            &length
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["intrinsic_type"]
+    }
+}
+
+/// The length of a `character` type, given either as the first positional
+/// argument or as `len=`
+fn character_length<'a>(node: &Node<'a>, src: &str) -> Option<Node<'a>> {
+    if !node
+        .child(0)?
+        .to_text(src)?
+        .eq_ignore_ascii_case("character")
+    {
+        return None;
+    }
+
+    let kind = node.child_by_field_name("kind")?;
+    kind.named_children(&mut kind.walk()).find_map(|child| {
+        if child.kind() != "keyword_argument" {
+            return Some(child);
+        }
+        let name = child.child_by_field_name("name")?.to_text(src)?;
+        if name.eq_ignore_ascii_case("len") {
+            child.child_by_field_name("value")
+        } else {
+            None
+        }
+    })
+}
+
+fn is_zero_literal(literal: &str) -> bool {
+    let digits = literal.split('_').next().unwrap_or_default();
+    !digits.is_empty() && digits.chars().all(|c| c == '0')
+}
+
+/// Is `name` a `parameter` initialised to a literal zero?
+fn is_zero_parameter(node: &Node, name: &str, src: &str) -> bool {
+    let Some(declaration) = find_variable_declaration(node, name, src) else {
+        return false;
+    };
+    let is_parameter = declaration
+        .children_by_field_name("attribute", &mut declaration.walk())
+        .filter_map(|attribute| attribute.to_text(src))
+        .any(|attribute| attribute.eq_ignore_ascii_case("parameter"));
+    if !is_parameter {
+        return false;
+    }
+    declaration
+        .children_by_field_name("declarator", &mut declaration.walk())
+        .filter(|declarator| declarator.kind() == "init_declarator")
+        .filter(|declarator| {
+            declarator_name(declarator, src)
+                .is_some_and(|declared| declared.eq_ignore_ascii_case(name))
+        })
+        .filter_map(|declarator| declarator.child_by_field_name("right")?.to_text(src))
+        .any(is_zero_literal)
+}
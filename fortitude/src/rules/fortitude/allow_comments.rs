@@ -0,0 +1,61 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex;
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `allow` comments with a trailing comma after the last rule.
+///
+/// ## Why is this bad?
+/// A trailing comma, as in `! allow(C001, C002,)`, is usually left over from
+/// editing the list of rules, and may be a sign that a rule was accidentally
+/// deleted. Fortitude ignores the empty entry, but it should be removed to
+/// keep the comment tidy.
+///
+/// ## Example
+/// ```f90
+/// ! allow(T001, S061,)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// ! allow(T001, S061)
+/// ```
+#[violation]
+pub struct AllowCommentTrailingComma {}
+
+impl AlwaysFixableViolation for AllowCommentTrailingComma {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("trailing comma in 'allow' comment")
+    }
+
+    fn fix_title(&self) -> String {
+        format!("Remove trailing comma")
+    }
+}
+
+impl AstRule for AllowCommentTrailingComma {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = node.to_text(src.source_text())?;
+        let captures = regex!(r"^! allow\(.*?(\s*,\s*)\)\s*$").captures(text)?;
+        let comma = captures.get(1)?;
+
+        let comment_start = TextSize::try_from(node.start_byte()).unwrap();
+        let range = TextRange::new(
+            comment_start + TextSize::try_from(comma.start()).unwrap(),
+            comment_start + TextSize::try_from(comma.end()).unwrap(),
+        );
+        let fix = Fix::safe_edit(Edit::range_deletion(range));
+        some_vec!(Diagnostic::new(Self {}, range).with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["comment"]
+    }
+}
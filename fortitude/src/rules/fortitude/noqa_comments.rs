@@ -0,0 +1,55 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `noqa` comments.
+///
+/// ## Why is this bad?
+/// Fortitude accepts `! noqa` and `! noqa: <codes>` comments as an alias for
+/// `allow` comments, to ease the transition for users of other linters. A bare
+/// `! noqa` allows every rule on that line, which can easily hide unrelated
+/// problems. `allow` comments are the preferred way to suppress diagnostics.
+///
+/// ## Example
+/// ```f90
+/// logical*4 :: flag  ! noqa: T021
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// ! allow(T021)
+/// logical*4 :: flag
+/// ```
+#[violation]
+pub struct NoqaComment {}
+
+impl Violation for NoqaComment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'noqa' comment used instead of 'allow'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with an '! allow(...)' comment on the previous line".to_string())
+    }
+}
+
+impl AstRule for NoqaComment {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = node.to_text(src.source_text())?;
+        if regex_is_match!(r"(?i)^!\s*noqa(:.*)?\s*$", text) {
+            return some_vec!(Diagnostic::from_node(Self {}, node));
+        }
+        None
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["comment"]
+    }
+}
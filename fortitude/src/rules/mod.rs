@@ -3,11 +3,14 @@
 #[macro_use]
 mod macros;
 pub(crate) mod bugprone;
+pub(crate) mod correctness;
 pub(crate) mod error;
 pub(crate) mod filesystem;
+pub(crate) mod fortitude;
 pub(crate) mod io;
 pub(crate) mod modules;
 pub(crate) mod obsolescent;
+pub(crate) mod portability;
 pub(crate) mod precision;
 pub(crate) mod readability;
 pub(crate) mod style;
@@ -88,6 +91,26 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
         (Style, "071") => (RuleGroup::Stable, Ast, style::double_colon_in_decl::MissingDoubleColon),
         (Style, "101") => (RuleGroup::Stable, Text, style::whitespace::TrailingWhitespace),
         (Style, "102") => (RuleGroup::Stable, Ast, style::whitespace::IncorrectSpaceBeforeComment),
+        (Style, "481") => (RuleGroup::Preview, Ast, style::keywords::InconsistentKeywordCase),
+        (Style, "491") => (RuleGroup::Preview, Ast, style::whitespace::IncorrectSpaceAroundOperator),
+        (Style, "501") => (RuleGroup::Preview, Ast, style::whitespace::MultipleBlankLines),
+        (Style, "511") => (RuleGroup::Preview, Ast, style::contains::EmptyContains),
+        (Style, "521") => (RuleGroup::Preview, Text, style::continuation::MisalignedContinuation),
+        (Style, "541") => (RuleGroup::Preview, Ast, style::use_position::MisplacedUseStatement),
+        (Style, "551") => (RuleGroup::Preview, Ast, style::end_statements::MismatchedEndStatementName),
+        (Style, "561") => (RuleGroup::Preview, Ast, style::opaque_types::InaccessibleDerivedType),
+        (Style, "571") => (RuleGroup::Preview, Ast, style::parameter_declarations::MixedParameterDeclaration),
+        (Style, "581") => (RuleGroup::Preview, Ast, style::implicit_statements::ImplicitLetterRanges),
+        (Style, "591") => (RuleGroup::Preview, Ast, style::use_only::InconsistentUseOnly),
+        (Style, "601") => (RuleGroup::Preview, Ast, style::module_procedures::UnknownModuleProcedure),
+        (Style, "611") => (RuleGroup::Preview, Ast, style::whitespace::CommentSpacingOutOfRange),
+        (Style, "621") => (RuleGroup::Preview, Ast, style::select_case::UnreachableCase),
+        (Style, "631") => (RuleGroup::Preview, Ast, style::contains::TooManyInternalProcedures),
+        (Style, "641") => (RuleGroup::Preview, Ast, style::kind_parameters::LiteralKindWithIsoFortranEnv),
+        (Style, "651") => (RuleGroup::Preview, Ast, style::declaration_position::DeclarationAfterExecutable),
+        (Style, "661") => (RuleGroup::Preview, Ast, style::todo_comments::TodoComment),
+        (Style, "671") => (RuleGroup::Preview, Ast, style::do_loops::SingleStatementDoLoop),
+        (Style, "681") => (RuleGroup::Preview, Ast, style::use_renames::DuplicateUseRename),
 
         (Typing, "001") => (RuleGroup::Stable, Ast, typing::implicit_typing::ImplicitTyping),
         (Typing, "002") => (RuleGroup::Stable, Ast, typing::implicit_typing::InterfaceImplicitTyping),
@@ -108,6 +131,11 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
         (Obsolescent, "021") => (RuleGroup::Stable, Ast, obsolescent::entry_statement::EntryStatement),
         (Obsolescent, "031") => (RuleGroup::Preview, Ast, obsolescent::specific_names::SpecificName),
         (Obsolescent, "041") => (RuleGroup::Preview, Ast, obsolescent::computed_goto::ComputedGoTo),
+        (Obsolescent, "151") => (RuleGroup::Preview, Text, obsolescent::include_statements::NonStandardInclude),
+        (Obsolescent, "161") => (RuleGroup::Preview, Ast, obsolescent::openmp::ThreadprivateCommonBlock),
+        (Obsolescent, "171") => (RuleGroup::Preview, Ast, obsolescent::mpi_routines::DeprecatedMpiRoutine),
+        (Obsolescent, "181") => (RuleGroup::Preview, Ast, obsolescent::mpi_module::LegacyMpiModule),
+        (Obsolescent, "191") => (RuleGroup::Preview, Ast, obsolescent::format_statements::SingleUseFormat),
 
         (Precision, "001") => (RuleGroup::Stable, Ast, precision::kind_suffixes::NoRealSuffix),
         (Precision, "011") => (RuleGroup::Stable, Ast, precision::double_precision::DoublePrecision),
@@ -127,6 +155,61 @@ pub fn code_to_rule(category: Category, code: &str) -> Option<(RuleGroup, Rule)>
 
         (Bugprone, "001") => (RuleGroup::Preview, Ast, bugprone::select_default::MissingDefaultCase),
 
+        (Correctness, "541") => (RuleGroup::Preview, Ast, correctness::loop_variables::ModifiedLoopVariable),
+        (Correctness, "551") => (RuleGroup::Preview, Ast, correctness::integer_division::IntegerDivisionInRealContext),
+        (Correctness, "561") => (RuleGroup::Preview, Ast, correctness::labelled_do::UnlabelledEndDo),
+        (Correctness, "571") => (RuleGroup::Preview, Ast, correctness::real_literals::IncompleteRealLiteral),
+        (Correctness, "581") => (RuleGroup::Preview, Ast, correctness::real_subscripts::RealLiteralWhereIntegerExpected),
+        (Correctness, "591") => (RuleGroup::Preview, Ast, correctness::intrinsic_modules::AmbiguousIntrinsicModule),
+        (Correctness, "601") => (RuleGroup::Preview, Ast, correctness::volatile::UnjustifiedVolatile),
+        (Correctness, "611") => (RuleGroup::Preview, Ast, correctness::protected::ProtectedVariableModified),
+        (Correctness, "631") => (RuleGroup::Preview, Ast, correctness::host_association::ShadowedHostVariable),
+        (Correctness, "641") => (RuleGroup::Preview, Ast, correctness::empty_function::EmptyFunctionBody),
+        (Correctness, "651") => (RuleGroup::Preview, Ast, correctness::c_pointers::UncheckedCPointer),
+        (Correctness, "661") => (RuleGroup::Preview, Ast, correctness::if_labels::MismatchedEndIfLabel),
+        (Correctness, "671") => (RuleGroup::Preview, Ast, correctness::associate_names::AssociateShadowsUseName),
+        (Correctness, "681") => (RuleGroup::Preview, Ast, correctness::allocate_mold::MismatchedAllocateMold),
+        (Correctness, "691") => (RuleGroup::Preview, Ast, correctness::concurrent_branching::BranchInConcurrentLoop),
+        (Correctness, "701") => (RuleGroup::Preview, Ast, correctness::read_only_units::WriteToReadOnlyUnit),
+        (Correctness, "711") => (RuleGroup::Preview, Ast, correctness::zero_length_character::ZeroLengthCharacter),
+        (Correctness, "721") => (RuleGroup::Preview, Ast, correctness::real_literal_range::RealLiteralOutOfRange),
+        (Correctness, "741") => (RuleGroup::Preview, Ast, correctness::intrinsic_arguments::RealIntrinsicArgument),
+        (Correctness, "751") => (RuleGroup::Preview, Ast, correctness::coarrays::CoarrayDeclaration),
+        (Correctness, "761") => (RuleGroup::Preview, Ast, correctness::random_seed::RandomNumberWithoutSeed),
+        (Correctness, "771") => (RuleGroup::Preview, Ast, correctness::file_positioning::NonSequentialFilePositioning),
+        (Correctness, "781") => (RuleGroup::Preview, Ast, correctness::move_alloc::NonAllocatableMoveAlloc),
+        (Correctness, "791") => (RuleGroup::Preview, Ast, correctness::associate_constants::ConstantAssociateSelector),
+        (Correctness, "801") => (RuleGroup::Preview, Ast, correctness::error_stop::ErrorStopWithoutMessage),
+        (Correctness, "821") => (RuleGroup::Preview, Ast, correctness::status_inquiry::AllocatedNonAllocatable),
+        (Correctness, "831") => (RuleGroup::Preview, Ast, correctness::status_inquiry::AssociatedNonPointer),
+        (Correctness, "841") => (RuleGroup::Preview, Ast, correctness::intent_in_arguments::IntentInPassedAsModifiable),
+        (Correctness, "851") => (RuleGroup::Preview, Ast, correctness::module_output::OutputInModule),
+        (Correctness, "861") => (RuleGroup::Preview, Ast, correctness::zero_length_character::NegativeCharacterLength),
+        (Correctness, "881") => (RuleGroup::Preview, Ast, correctness::pointer_intent::PointerIntent),
+        (Correctness, "891") => (RuleGroup::Preview, Ast, correctness::double_deallocate::DoubleDeallocate),
+        (Correctness, "901") => (RuleGroup::Preview, Ast, correctness::error_stop::StopCodeInModule),
+        (Correctness, "911") => (RuleGroup::Preview, Ast, correctness::function_result::ResultNamedAsArgument),
+        (Correctness, "921") => (RuleGroup::Preview, Ast, correctness::select_type::TypeIsWithExtensions),
+        (Correctness, "931") => (RuleGroup::Preview, Ast, correctness::namelist_names::NamelistShadowsVariable),
+        (Correctness, "941") => (RuleGroup::Preview, Ast, correctness::read_parameters::ReadIntoParameter),
+        (Correctness, "951") => (RuleGroup::Preview, Ast, correctness::sequence_types::SequenceTypeInBindC),
+        (Correctness, "961") => (RuleGroup::Preview, Ast, correctness::procedure_pointers::PureProcedureToImpurePointer),
+        (Correctness, "971") => (RuleGroup::Preview, Ast, correctness::mixed_kinds::MixedRealKinds),
+        (Correctness, "981") => (RuleGroup::Preview, Ast, correctness::module_output::ErrorOutputInModule),
+        (Correctness, "991") => (RuleGroup::Preview, Ast, correctness::optional_arguments::UnguardedOptionalInPure),
+        (Correctness, "1001") => (RuleGroup::Preview, Ast, correctness::value_intent::ValueWithOutputIntent),
+
+        (Fortitude, "006") => (RuleGroup::Preview, Ast, fortitude::noqa_comments::NoqaComment),
+        (Fortitude, "007") => (RuleGroup::Preview, Ast, fortitude::allow_comments::AllowCommentTrailingComma),
+
+        (Portability, "171") => (RuleGroup::Preview, Ast, portability::flush::FlushStatement),
+        (Portability, "181") => (RuleGroup::Preview, Ast, portability::format_labels::FormatLabel),
+        (Portability, "191") => (RuleGroup::Preview, Ast, portability::include_paths::IncludePathSeparator),
+        (Portability, "201") => (RuleGroup::Preview, Ast, portability::newunit::NewunitSpecifier),
+        (Portability, "211") => (RuleGroup::Preview, Ast, portability::output_unit::DefaultOutputUnit),
+        (Portability, "231") => (RuleGroup::Preview, Ast, portability::file_paths::HardCodedFilePath),
+        (Portability, "241") => (RuleGroup::Preview, Ast, portability::c_kinds::OptionalCKind),
+
         // Rules for testing fortitude
         // Couldn't get a separate `Testing` category working for some reason
         #[cfg(any(feature = "test-rules", test))]
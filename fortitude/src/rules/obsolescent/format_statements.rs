@@ -0,0 +1,131 @@
+use crate::ast::{scoping_unit, FortitudeNode};
+use crate::rules::obsolescent::include_statements::FIXED_FORM_EXTS;
+use crate::rules::portability::format_labels::{format_label_reference, parse_format_line};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::{OneIndexed, SourceFile};
+use ruff_text_size::{TextRange, TextSize};
+use std::collections::HashMap;
+use std::path::Path;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for labelled `format` statements in free-form source that are only
+/// used by a single `read`, `write`, or `print` statement.
+///
+/// ## Why is this bad?
+/// A `format` statement that is only used once is easier to follow as an
+/// inline character format, where it can be read alongside the data it
+/// applies to, and there is no label to keep track of. Formats shared between
+/// several statements are left alone, though a named character constant may
+/// be clearer for those too.
+///
+/// ## Example
+/// ```f90
+/// write (*, 100) x
+/// 100 format (F10.3)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// write (*, '(F10.3)') x
+/// ```
+#[violation]
+pub struct SingleUseFormat {
+    label: u32,
+}
+
+impl Violation for SingleUseFormat {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { label } = self;
+        format!("'format' statement '{label}' is only used once")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with an inline format string".to_string())
+    }
+}
+
+impl AstRule for SingleUseFormat {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let is_fixed_form = Path::new(src.name())
+            .extension()
+            .is_some_and(|ext| FIXED_FORM_EXTS.iter().any(|&x| x == ext));
+        if is_fixed_form {
+            return None;
+        }
+
+        let text = src.source_text();
+        let in_this_unit = |child: &Node| scoping_unit(child).is_some_and(|unit| unit == *node);
+
+        let mut references: HashMap<u32, Vec<Node>> = HashMap::new();
+        for statement in node.named_descendants().filter(|child| {
+            matches!(
+                child.kind(),
+                "read_statement" | "write_statement" | "print_statement"
+            ) && in_this_unit(child)
+        }) {
+            let Some(reference) = format_label_reference(&statement, text) else {
+                continue;
+            };
+            let Some(label) = reference.to_text(text).and_then(|l| l.parse().ok()) else {
+                continue;
+            };
+            references.entry(label).or_default().push(reference);
+        }
+
+        let source = src.to_source_code();
+        let violations = node
+            .named_descendants()
+            .filter(|child| child.kind() == "format_statement" && in_this_unit(child))
+            .filter_map(|statement| {
+                let line = source.line_index(TextSize::try_from(statement.start_byte()).unwrap());
+                let line_text = source.line_text(line);
+                let (label, format) = parse_format_line(line_text)?;
+                let [reference] = references.get(&label)?.as_slice() else {
+                    return None;
+                };
+
+                // Report the whole line, including the label
+                let indent = line_text.len() - line_text.trim_start().len();
+                let start = source.line_start(line) + TextSize::try_from(indent).unwrap();
+                let length = TextSize::try_from(line_text.trim().len()).unwrap();
+                let diagnostic = Diagnostic::new(Self { label }, TextRange::at(start, length));
+                // Only fix statements on a single line, without a comment
+                // that would be lost when the line is removed
+                let whole_statement = format.ends_with(')') && !line_text.contains('!');
+                let Some(quoted) = quote(&format).filter(|_| whole_statement) else {
+                    return Some(diagnostic);
+                };
+                let next_line = OneIndexed::from_zero_indexed(line.to_zero_indexed() + 1);
+                let delete = Edit::range_deletion(TextRange::new(
+                    source.line_start(line),
+                    source.line_start(next_line),
+                ));
+                let replace = reference.edit_replacement(src, quoted);
+                Some(diagnostic.with_fix(Fix::safe_edits(replace, [delete])))
+            })
+            .collect();
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["program", "subroutine", "function", "module_procedure"]
+    }
+}
+
+/// Quote a format specification as a character literal, if possible
+fn quote(format: &str) -> Option<String> {
+    if !format.contains('\'') {
+        Some(format!("'{format}'"))
+    } else if !format.contains('"') {
+        Some(format!("\"{format}\""))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,84 @@
+use crate::settings::Settings;
+use crate::TextRule;
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::{OneIndexed, SourceFile};
+use ruff_text_size::{TextRange, TextSize};
+use std::path::Path;
+
+/// File extensions conventionally used for fixed-form source
+pub(crate) const FIXED_FORM_EXTS: &[&str] = &["f", "F", "for", "FOR", "ftn", "FTN", "f77", "F77"];
+
+/// ## What it does
+/// Checks for `include` lines in fixed-form source files that start before
+/// column 7, or that use the non-standard `$include` directive.
+///
+/// ## Why is this bad?
+/// In fixed-form source, columns 1 to 6 are reserved for comment markers,
+/// statement labels and continuation characters, so an `include` line must
+/// start in column 7 or later. Some compilers accept `include` lines anywhere,
+/// but others will fail to recognise them. The `$include` form is a vendor
+/// extension and is not understood by most compilers.
+///
+/// This rule only applies to files with a fixed-form extension, such as `.f`
+/// or `.for`. Such files must be added with `--file-extensions` to be checked.
+///
+/// ## Example
+/// ```f
+/// $include: 'header.h'
+/// ```
+///
+/// Use instead:
+/// ```f
+///       include 'header.h'
+/// ```
+#[violation]
+pub struct NonStandardInclude {
+    directive: bool,
+}
+
+impl Violation for NonStandardInclude {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        if self.directive {
+            format!("non-standard '$include' directive, use an 'include' line")
+        } else {
+            format!("'include' line starts before column 7 in fixed-form source")
+        }
+    }
+}
+
+impl TextRule for NonStandardInclude {
+    fn check(_settings: &Settings, source_file: &SourceFile) -> Vec<Diagnostic> {
+        let is_fixed_form = Path::new(source_file.name())
+            .extension()
+            .is_some_and(|ext| FIXED_FORM_EXTS.iter().any(|&x| x == ext));
+        if !is_fixed_form {
+            return vec![];
+        }
+
+        let source = source_file.to_source_code();
+        let mut violations = Vec::new();
+        for (idx, line) in source.text().lines().enumerate() {
+            let Some((_, indent, keyword)) = regex_captures!(r"^(?i)([ \t]*)(\$?include)\b", line)
+            else {
+                continue;
+            };
+            let directive = keyword.starts_with('$');
+            // A leading tab is a common extension meaning "start in column 7"
+            if !directive && (indent.contains('\t') || indent.len() >= 6) {
+                continue;
+            }
+
+            let line_start = source.line_start(OneIndexed::from_zero_indexed(idx));
+            let start = line_start + TextSize::try_from(indent.len()).unwrap();
+            let end = start + TextSize::try_from(keyword.len()).unwrap();
+            violations.push(Diagnostic::new(
+                Self { directive },
+                TextRange::new(start, end),
+            ));
+        }
+        violations
+    }
+}
@@ -1,6 +1,11 @@
 pub mod common_blocks;
 pub mod computed_goto;
 pub mod entry_statement;
+pub mod format_statements;
+pub mod include_statements;
+pub mod mpi_module;
+pub mod mpi_routines;
+pub mod openmp;
 pub mod specific_names;
 pub mod statement_functions;
 
@@ -14,7 +19,7 @@ mod tests {
     use test_case::test_case;
 
     use crate::registry::Rule;
-    use crate::settings::Settings;
+    use crate::settings::{FortranStandard, Settings};
     use crate::test::test_path;
 
     #[test_case(Rule::StatementFunction, Path::new("OB001.f90"))]
@@ -22,6 +27,10 @@ mod tests {
     #[test_case(Rule::EntryStatement, Path::new("OB021.f90"))]
     #[test_case(Rule::SpecificName, Path::new("OB031.f90"))]
     #[test_case(Rule::ComputedGoTo, Path::new("OB041.f90"))]
+    #[test_case(Rule::NonStandardInclude, Path::new("OB151.f"))]
+    #[test_case(Rule::ThreadprivateCommonBlock, Path::new("OB161.f90"))]
+    #[test_case(Rule::DeprecatedMpiRoutine, Path::new("OB171.f90"))]
+    #[test_case(Rule::SingleUseFormat, Path::new("OB191.f90"))]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -32,4 +41,47 @@ mod tests {
         assert_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test_case(Rule::LegacyMpiModule, Path::new("OB181.f90"))]
+    fn legacy_mpi_module_prefer_mpi_f08(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            prefer_mpi_f08: true,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("obsolescent").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::LegacyMpiModule, Path::new("OB181.f90"))]
+    fn legacy_mpi_module_default(rule_code: Rule, path: &Path) -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("obsolescent").join(path).as_path(),
+            &[rule_code],
+            &Settings::default(),
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::LegacyMpiModule, Path::new("OB181.f90"))]
+    fn legacy_mpi_module_f2003(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            prefer_mpi_f08: true,
+            target_std: FortranStandard::F2003,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("obsolescent").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
 }
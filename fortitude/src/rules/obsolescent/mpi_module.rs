@@ -0,0 +1,73 @@
+use crate::ast::FortitudeNode;
+use crate::settings::{FortranStandard, Settings};
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `use mpi` where the Fortran 2008 bindings, `use mpi_f08`, could
+/// be used instead.
+///
+/// This rule only applies when `--prefer-mpi-f08` is set and the target
+/// standard is Fortran 2008 or later, as migrating existing code may be a
+/// significant amount of work.
+///
+/// ## Why is this bad?
+/// The `mpi` module uses plain `integer` handles for communicators, datatypes,
+/// requests, and so on, so the compiler can't tell them apart, and many
+/// routines can't have their arguments checked at all. The `mpi_f08` module
+/// provides distinct derived types for each kind of handle, makes the `ierror`
+/// argument optional, and is the only MPI binding that is fully consistent with
+/// the Fortran standard.
+///
+/// Note that switching modules requires changing the declarations of any MPI
+/// handles, for example from `integer` to `type(MPI_Comm)`.
+///
+/// ## Example
+/// ```f90
+/// use mpi
+/// integer :: comm
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// use mpi_f08
+/// type(MPI_Comm) :: comm
+/// ```
+#[violation]
+pub struct LegacyMpiModule {}
+
+impl Violation for LegacyMpiModule {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'use mpi' instead of Fortran 2008 'mpi_f08' module")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use 'mpi_f08'".to_string())
+    }
+}
+
+impl AstRule for LegacyMpiModule {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if !settings.prefer_mpi_f08 || settings.target_std < FortranStandard::F2008 {
+            return None;
+        }
+
+        let module_name = node.child_with_name("module_name")?;
+        if !module_name
+            .to_text(src.source_text())?
+            .eq_ignore_ascii_case("mpi")
+        {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(Self {}, &module_name))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["use_statement"]
+    }
+}
@@ -0,0 +1,88 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// MPI routines deprecated in MPI-2 and removed in MPI-3, with their replacements
+const DEPRECATED_MPI_ROUTINES: &[(&str, &str)] = &[
+    ("mpi_address", "mpi_get_address"),
+    ("mpi_attr_delete", "mpi_comm_delete_attr"),
+    ("mpi_attr_get", "mpi_comm_get_attr"),
+    ("mpi_attr_put", "mpi_comm_set_attr"),
+    ("mpi_errhandler_create", "mpi_comm_create_errhandler"),
+    ("mpi_errhandler_get", "mpi_comm_get_errhandler"),
+    ("mpi_errhandler_set", "mpi_comm_set_errhandler"),
+    ("mpi_keyval_create", "mpi_comm_create_keyval"),
+    ("mpi_keyval_free", "mpi_comm_free_keyval"),
+    ("mpi_type_extent", "mpi_type_get_extent"),
+    ("mpi_type_hindexed", "mpi_type_create_hindexed"),
+    ("mpi_type_hvector", "mpi_type_create_hvector"),
+    ("mpi_type_lb", "mpi_type_get_extent"),
+    ("mpi_type_struct", "mpi_type_create_struct"),
+    ("mpi_type_ub", "mpi_type_get_extent"),
+];
+
+/// ## What it does
+/// Checks for calls to MPI routines that were deprecated in MPI-2 and removed
+/// in MPI-3.
+///
+/// ## Why is this bad?
+/// These routines are no longer part of the MPI standard, and recent MPI
+/// libraries may not provide them at all. Most of them were replaced because
+/// they used default `integer` arguments for addresses and extents, which can
+/// overflow on 64-bit systems. The replacements take
+/// `integer(kind=MPI_ADDRESS_KIND)` arguments instead, so switching over may
+/// require changing some declarations too.
+///
+/// ## Example
+/// ```f90
+/// call MPI_Type_struct(count, lengths, displacements, types, new_type, ierr)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// call MPI_Type_create_struct(count, lengths, displacements, types, new_type, ierr)
+/// ```
+#[violation]
+pub struct DeprecatedMpiRoutine {
+    name: String,
+    replacement: &'static str,
+}
+
+impl Violation for DeprecatedMpiRoutine {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name, .. } = self;
+        format!("MPI routine '{name}' was removed in MPI-3")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { replacement, .. } = self;
+        Some(format!("Use '{replacement}'"))
+    }
+}
+
+impl AstRule for DeprecatedMpiRoutine {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let name_node = node.named_child(0)?;
+        let name = name_node.to_text(src.source_text())?;
+        let lowercase_name = name.to_lowercase();
+        let (_, replacement) = DEPRECATED_MPI_ROUTINES
+            .iter()
+            .find(|(deprecated, _)| *deprecated == lowercase_name)?;
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string(),
+                replacement,
+            },
+            &name_node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine_call"]
+    }
+}
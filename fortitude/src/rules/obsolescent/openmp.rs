@@ -0,0 +1,62 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::{regex_captures, regex_find};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for OpenMP `threadprivate` directives that name a common block.
+///
+/// ## Why is this bad?
+/// Making a common block thread-private ties OpenMP code to an obsolescent
+/// Fortran feature (see `OB011`), and every scope that declares the common
+/// block must repeat the directive. Module variables can be made
+/// thread-private directly, and only need the directive once.
+///
+/// ## Example
+/// ```f90
+/// common /counters/ hits, misses
+/// !$omp threadprivate(/counters/)
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// module counters
+///   integer :: hits, misses
+///   !$omp threadprivate(hits, misses)
+/// end module counters
+/// ```
+#[violation]
+pub struct ThreadprivateCommonBlock {
+    name: String,
+}
+
+impl Violation for ThreadprivateCommonBlock {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("common block '/{name}/' in OpenMP 'threadprivate' directive")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Move the data into a module and make those variables thread-private".to_string())
+    }
+}
+
+impl AstRule for ThreadprivateCommonBlock {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = node.to_text(src.source_text())?;
+        let (_, items) = regex_captures!(r"(?i)^!\$omp\s+threadprivate\s*\((.*)\)", text)?;
+        let name = regex_find!(r"/\s*\w+\s*/", items)?
+            .trim_matches(|c: char| c == '/' || c.is_whitespace())
+            .to_string();
+        some_vec!(Diagnostic::from_node(Self { name }, node))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["comment"]
+    }
+}
@@ -0,0 +1,107 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// Kinds from `iso_c_binding` that are commonly negative, meaning the type
+/// isn't available
+const OPTIONAL_C_KINDS: &[&str] = &[
+    "c_long_double",
+    "c_long_double_complex",
+    "c_float128",
+    "c_float128_complex",
+    "c_int128_t",
+    "c_int_least128_t",
+    "c_int_fast128_t",
+];
+
+/// Comparison operators, for spotting run-time checks of a kind's value
+const COMPARISONS: &[&str] = &[
+    "==", "/=", "<", ">", ".eq.", ".ne.", ".lt.", ".le.", ".gt.", ".ge.",
+];
+
+/// ## What it does
+/// Checks for declarations using kinds from `iso_c_binding` that aren't
+/// available on every platform, such as `c_float128` or `c_long_double`,
+/// without checking their value anywhere in the file.
+///
+/// ## Why is this bad?
+/// Named constants in `iso_c_binding` are negative if the companion C
+/// processor has no matching type. Some, like `c_float128` and `c_int128_t`,
+/// are compiler extensions, and others, like `c_long_double`, are missing on
+/// some platforms. Using a negative kind is a compile error, so code that
+/// relies on these kinds will fail to build with other compilers or on other
+/// machines. Either use a kind that is always available, or check the value
+/// first and provide a fallback.
+///
+/// ## Example
+/// ```f90
+/// use, intrinsic :: iso_c_binding, only: c_float128
+/// real(c_float128) :: x
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// use, intrinsic :: iso_c_binding, only: c_double
+/// real(c_double) :: x
+/// ```
+#[violation]
+pub struct OptionalCKind {
+    name: String,
+}
+
+impl Violation for OptionalCKind {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'{name}' may not be available on all platforms")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { name } = self;
+        Some(format!(
+            "Use a kind that is always available, or check that '{name}' is not negative"
+        ))
+    }
+}
+
+impl AstRule for OptionalCKind {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let kind = node.child_by_field_name("kind")?;
+        let (_, name) =
+            regex_captures!(r"^\(\s*(?i:kind\s*=\s*)?(\w+)\s*\)$", kind.to_text(text)?)?;
+        let lowercase_name = name.to_lowercase();
+        if !OPTIONAL_C_KINDS.contains(&lowercase_name.as_str()) {
+            return None;
+        }
+        if is_checked(&lowercase_name, text) {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string()
+            },
+            &kind
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["intrinsic_type"]
+    }
+}
+
+/// Is `name` compared against anything in the file, as in
+/// `if (c_float128 < 0) error stop`?
+fn is_checked(name: &str, src: &str) -> bool {
+    let src = src.to_lowercase();
+    src.match_indices(name).any(|(index, _)| {
+        let rest = src[index + name.len()..].trim_start();
+        COMPARISONS.iter().any(|op| rest.starts_with(op))
+    })
+}
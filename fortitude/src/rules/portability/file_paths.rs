@@ -0,0 +1,91 @@
+use crate::ast::{is_keyword_argument, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `open` statements where `file=` is a string literal containing
+/// an absolute or OS-specific path, such as `/home/user/data.txt`,
+/// `~/data.txt`, `C:\data\file.txt`, or `data\file.txt`.
+///
+/// ## Why is this bad?
+/// Absolute paths only exist on the machine they were written on, `~` is
+/// expanded by the shell and not by the Fortran runtime, and backslashes are
+/// only directory separators on Windows. Relative paths using `/` work on all
+/// common platforms, but where a file may live elsewhere, it's better to build
+/// the path at run time from an environment variable or a command line
+/// argument, using `get_environment_variable` or `get_command_argument`.
+///
+/// ## Example
+/// ```f90
+/// open(newunit=unit, file="/home/user/data.txt", action="read")
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// call get_command_argument(1, filename)
+/// open(newunit=unit, file=trim(filename), action="read")
+/// ```
+#[violation]
+pub struct HardCodedFilePath {
+    path: String,
+    kind: &'static str,
+}
+
+impl Violation for HardCodedFilePath {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { path, kind } = self;
+        format!("file '{path}' is {kind}")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Build the path from an environment variable or command line argument".to_string())
+    }
+}
+
+impl AstRule for HardCodedFilePath {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let file = node
+            .named_children(&mut node.walk())
+            .find(|child| is_keyword_argument(child, "file", text))?
+            .child_by_field_name("value")?;
+        if file.kind() != "string_literal" {
+            return None;
+        }
+        let path = file.to_text(text)?.trim_matches(|c| c == '"' || c == '\'');
+        let kind = path_kind(path)?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                path: path.to_string(),
+                kind,
+            },
+            &file
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["open_statement"]
+    }
+}
+
+/// Describes what makes `path` non-portable, if anything
+fn path_kind(path: &str) -> Option<&'static str> {
+    let bytes = path.as_bytes();
+    if path.starts_with('/') {
+        Some("an absolute path")
+    } else if path.starts_with('~') {
+        Some("relative to the home directory")
+    } else if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        Some("an absolute Windows path")
+    } else if path.contains('\\') {
+        Some("a Windows path")
+    } else {
+        None
+    }
+}
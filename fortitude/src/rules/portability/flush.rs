@@ -0,0 +1,66 @@
+use crate::ast::FortitudeNode;
+use crate::settings::{FortranStandard, Settings};
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `flush` statements when targeting Fortran 95 or earlier, as set
+/// by `--target-std`.
+///
+/// ## Why is this bad?
+/// The `flush` statement was introduced in Fortran 2003, so code that must
+/// build with older compilers can't rely on it. Some of those compilers
+/// provide their own non-standard ways to flush a unit, such as a `flush`
+/// subroutine, but these differ between vendors.
+///
+/// ## Example
+/// ```f90
+/// write (unit, *) "progress"
+/// flush (unit)
+/// ```
+#[violation]
+pub struct FlushStatement {
+    target: FortranStandard,
+}
+
+impl Violation for FlushStatement {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { target } = self;
+        format!("'flush' statement requires Fortran 2003, but the target is {target}")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove 'flush', or use a compiler-specific alternative".to_string())
+    }
+}
+
+impl AstRule for FlushStatement {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.target_std >= FortranStandard::F2003 {
+            return None;
+        }
+
+        let keyword = node.child(0)?;
+        if !keyword
+            .to_text(src.source_text())?
+            .eq_ignore_ascii_case("flush")
+        {
+            return None;
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                target: settings.target_std
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["file_position_statement"]
+    }
+}
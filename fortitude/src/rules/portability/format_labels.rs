@@ -0,0 +1,136 @@
+use crate::ast::{is_keyword_argument, scoping_unit, FortitudeNode};
+use crate::rules::obsolescent::include_statements::FIXED_FORM_EXTS;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::TextSize;
+use std::path::Path;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `read` and `write` statements in free-form source that refer to
+/// a labelled `format` statement.
+///
+/// ## Why is this bad?
+/// Format labels are a holdover from fixed-form Fortran. The format is
+/// defined away from the statement that uses it, and the label has to be kept
+/// in step when code is moved or copied between files. An inline character
+/// format keeps everything in one place:
+///
+/// ```f90
+/// write (*, '(F10.3)') x
+/// ```
+///
+/// ## Example
+/// ```f90
+/// write (*, 100) x
+/// 100 format (F10.3)
+/// ```
+#[violation]
+pub struct FormatLabel {
+    label: String,
+    statement: String,
+    format: Option<String>,
+}
+
+impl Violation for FormatLabel {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            label, statement, ..
+        } = self;
+        format!("format label '{label}' in '{statement}' statement")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        match &self.format {
+            Some(format) => Some(format!("Replace with inline format '{format}'")),
+            None => Some("Replace with an inline format string".to_string()),
+        }
+    }
+}
+
+impl AstRule for FormatLabel {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let is_fixed_form = Path::new(src.name())
+            .extension()
+            .is_some_and(|ext| FIXED_FORM_EXTS.iter().any(|&x| x == ext));
+        if is_fixed_form {
+            return None;
+        }
+
+        let text = src.source_text();
+        let format = format_label_reference(node, text)?;
+        let label = format.to_text(text)?;
+
+        let statement = node.child(0)?.to_text(text)?.to_lowercase();
+        some_vec!(Diagnostic::from_node(
+            Self {
+                label: label.to_string(),
+                statement,
+                format: find_format(node, src, label),
+            },
+            &format
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["read_statement", "write_statement"]
+    }
+}
+
+/// The label of the `format` statement used by an IO statement, if it refers
+/// to one
+pub(crate) fn format_label_reference<'a>(node: &Node<'a>, src: &str) -> Option<Node<'a>> {
+    let format = if let Some(format) = node.child_with_name("format_identifier") {
+        format.child(0)?
+    } else {
+        node.named_children(&mut node.walk())
+            .find(|child| is_keyword_argument(child, "fmt", src))
+            .map(|child| child.child_by_field_name("value"))??
+    };
+    if !matches!(
+        format.kind(),
+        "statement_label_reference" | "number_literal"
+    ) {
+        return None;
+    }
+    let label = format.to_text(src)?;
+    if !label.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format)
+}
+
+/// Find the format specification of the `format` statement with the given label
+/// in the same scoping unit as `node`
+fn find_format(node: &Node, src: &SourceFile, label: &str) -> Option<String> {
+    let label = label.parse::<u32>().ok()?;
+    let unit = scoping_unit(node)?;
+    let source = src.to_source_code();
+    unit.named_descendants()
+        .filter(|child| {
+            child.kind() == "format_statement" && scoping_unit(child).is_some_and(|u| u == unit)
+        })
+        .find_map(|statement| {
+            let line = source.line_index(TextSize::try_from(statement.start_byte()).unwrap());
+            let (line_label, format) = parse_format_line(source.line_text(line))?;
+            (line_label == label).then_some(format)
+        })
+}
+
+/// Split a line containing a labelled `format` statement into its label and
+/// format specification
+pub(crate) fn parse_format_line(line: &str) -> Option<(u32, String)> {
+    let line = line.trim_start();
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    let label = line[..digits].parse::<u32>().ok()?;
+    let rest = line[digits..].trim_start();
+    if !rest.get(..6)?.eq_ignore_ascii_case("format") {
+        return None;
+    }
+    let format = rest[6..].split('!').next()?.trim();
+    format.starts_with('(').then(|| (label, format.to_string()))
+}
@@ -0,0 +1,64 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `include` lines with backslashes in the path.
+///
+/// ## Why is this bad?
+/// Backslash path separators are only understood on Windows, so an `include`
+/// line like `include 'path\file.inc'` will fail to find the file on other
+/// platforms. Forward slashes are accepted by compilers on all platforms.
+///
+/// ## Example
+/// ```f90
+/// include 'common\constants.inc'
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// include 'common/constants.inc'
+/// ```
+#[violation]
+pub struct IncludePathSeparator {
+    path: String,
+}
+
+impl AlwaysFixableViolation for IncludePathSeparator {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { path } = self;
+        format!("non-portable backslash in include path {path}")
+    }
+
+    fn fix_title(&self) -> String {
+        "Use forward slashes as path separators".to_string()
+    }
+}
+
+impl AstRule for IncludePathSeparator {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let path_node = node.named_child(0)?;
+        let path = path_node.to_text(src.source_text())?;
+        if !path.contains('\\') {
+            return None;
+        }
+
+        let fix = Fix::safe_edit(path_node.edit_replacement(src, path.replace('\\', "/")));
+        some_vec!(Diagnostic::from_node(
+            Self {
+                path: path.to_string()
+            },
+            &path_node
+        )
+        .with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["include_statement"]
+    }
+}
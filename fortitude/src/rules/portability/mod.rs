@@ -0,0 +1,110 @@
+pub mod c_kinds;
+pub mod file_paths;
+pub mod flush;
+pub mod format_labels;
+pub mod include_paths;
+pub mod newunit;
+pub mod output_unit;
+
+#[cfg(test)]
+mod tests {
+    use std::convert::AsRef;
+    use std::path::Path;
+
+    use anyhow::Result;
+    use insta::assert_snapshot;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::settings::{FortranStandard, Settings};
+    use crate::test::test_path;
+
+    #[test_case(Rule::FormatLabel, Path::new("PORT181.f90"))]
+    #[test_case(Rule::IncludePathSeparator, Path::new("PORT191.f90"))]
+    #[test_case(Rule::HardCodedFilePath, Path::new("PORT231.f90"))]
+    #[test_case(Rule::OptionalCKind, Path::new("PORT241.f90"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &Settings::default(),
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::FlushStatement, Path::new("PORT171.f90"))]
+    #[test_case(Rule::NewunitSpecifier, Path::new("PORT201.f90"))]
+    fn rules_f95(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            target_std: FortranStandard::F95,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::FlushStatement, Path::new("PORT171.f90"))]
+    fn rules_f2003(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            target_std: FortranStandard::F2003,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::NewunitSpecifier, Path::new("PORT201.f90"))]
+    fn rules_f2008(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            target_std: FortranStandard::F2008,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::DefaultOutputUnit, Path::new("PORT211.f90"))]
+    fn default_output_unit_required(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            require_explicit_output_unit: true,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::DefaultOutputUnit, Path::new("PORT211.f90"))]
+    fn default_output_unit_not_required(rule_code: Rule, path: &Path) -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("portability").join(path).as_path(),
+            &[rule_code],
+            &Settings::default(),
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+}
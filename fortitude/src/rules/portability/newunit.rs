@@ -0,0 +1,64 @@
+use crate::ast::is_keyword_argument;
+use crate::settings::{FortranStandard, Settings};
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `open` statements with a `newunit=` specifier when targeting
+/// Fortran 2003 or earlier, as set by `--target-std`.
+///
+/// ## Why is this bad?
+/// The `newunit=` specifier was introduced in Fortran 2008, so code that must
+/// build with older compilers can't rely on it. Instead, the unit number has
+/// to be chosen explicitly, for example by searching for a free unit with
+/// `inquire(unit=..., opened=...)`.
+///
+/// This rule is the counterpart to `magic-io-unit` (IO011), which recommends
+/// `newunit=` when targeting Fortran 2008 or later.
+///
+/// ## Example
+/// ```f90
+/// open(newunit=unit, file="example.txt", action="read")
+/// ```
+#[violation]
+pub struct NewunitSpecifier {
+    target: FortranStandard,
+}
+
+impl Violation for NewunitSpecifier {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { target } = self;
+        format!("'newunit=' requires Fortran 2008, but the target is {target}")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use an explicit unit number".to_string())
+    }
+}
+
+impl AstRule for NewunitSpecifier {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if settings.target_std >= FortranStandard::F2008 {
+            return None;
+        }
+
+        let newunit = node
+            .named_children(&mut node.walk())
+            .find(|child| is_keyword_argument(child, "newunit", src.source_text()))?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                target: settings.target_std
+            },
+            &newunit
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["open_statement"]
+    }
+}
@@ -0,0 +1,61 @@
+use crate::ast::FortitudeNode;
+use crate::rules::correctness::read_only_units::io_unit;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `write` statements to the default unit `*`, when
+/// `--require-explicit-output-unit` is set.
+///
+/// ## Why is this bad?
+/// `write(*, *)` is standard Fortran, but it hides which unit is being written
+/// to. Using `output_unit` from `iso_fortran_env` makes the destination
+/// explicit, and code that takes the unit from a variable or argument can
+/// easily be pointed at a file instead, for example in tests.
+///
+/// ## Example
+/// ```f90
+/// write(*, *) "Hello, world!"
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// use, intrinsic :: iso_fortran_env, only: output_unit
+/// ...
+/// write(output_unit, *) "Hello, world!"
+/// ```
+#[violation]
+pub struct DefaultOutputUnit {}
+
+impl Violation for DefaultOutputUnit {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'write' to default unit '*'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use 'output_unit' from 'iso_fortran_env'".to_string())
+    }
+}
+
+impl AstRule for DefaultOutputUnit {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if !settings.require_explicit_output_unit {
+            return None;
+        }
+
+        let unit = io_unit(node, src.source_text())?;
+        if unit.to_text(src.source_text())? != "*" {
+            return None;
+        }
+        some_vec!(Diagnostic::from_node(Self {}, &unit))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["write_statement"]
+    }
+}
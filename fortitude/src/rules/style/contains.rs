@@ -0,0 +1,148 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::{OneIndexed, SourceFile};
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `contains` statements that aren't followed by any procedures.
+///
+/// ## Why is this bad?
+/// A `contains` section with nothing in it is dead code, often left behind
+/// after procedures have been moved or deleted, and can be removed.
+///
+/// ## Example
+/// ```f90
+/// module foo
+///   implicit none (type, external)
+/// contains
+/// end module foo
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// module foo
+///   implicit none (type, external)
+/// end module foo
+/// ```
+#[violation]
+pub struct EmptyContains {}
+
+impl Violation for EmptyContains {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'contains' section has no procedures")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove empty 'contains'".to_string())
+    }
+}
+
+impl AstRule for EmptyContains {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let has_procedures = node
+            .named_children(&mut node.walk())
+            .any(|child| !matches!(child.kind(), "contains_statement" | "comment"));
+        if has_procedures {
+            return None;
+        }
+
+        let statement = node.child_with_name("contains_statement")?;
+        let keyword = statement.child(0)?;
+        let diagnostic = Diagnostic::from_node(Self {}, &keyword);
+
+        // Only remove the statement if it's on a line by itself
+        let source = src.to_source_code();
+        let line = source.line_index(TextSize::try_from(keyword.start_byte()).unwrap());
+        if !source
+            .line_text(line)
+            .trim()
+            .eq_ignore_ascii_case(keyword.to_text(src.source_text())?)
+        {
+            return some_vec!(diagnostic);
+        }
+        let next_line = OneIndexed::from_zero_indexed(line.to_zero_indexed() + 1);
+        let edit = Edit::range_deletion(TextRange::new(
+            source.line_start(line),
+            source.line_start(next_line),
+        ));
+        some_vec!(diagnostic.with_fix(Fix::safe_edit(edit)))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["internal_procedures"]
+    }
+}
+
+/// ## What it does
+/// Checks for subroutines and functions with too many internal procedures.
+/// The maximum can be changed using `--max-internal-procedures=N`, and
+/// defaults to 5.
+///
+/// ## Why is this bad?
+/// Internal procedures have access to every variable of their host, so a long
+/// `contains` section makes it hard to see how data flows through the outer
+/// procedure, and the internal procedures can't be reused or tested on their
+/// own. Moving them to a separate module keeps each procedure small and makes
+/// their interfaces explicit.
+#[violation]
+pub struct TooManyInternalProcedures {
+    name: String,
+    count: usize,
+    max_count: usize,
+}
+
+impl Violation for TooManyInternalProcedures {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            name,
+            count,
+            max_count,
+        } = self;
+        format!("'{name}' has too many internal procedures ({count} > {max_count})")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Move some procedures to a separate module".to_string())
+    }
+}
+
+impl AstRule for TooManyInternalProcedures {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let parent = node.parent()?;
+        if !matches!(parent.kind(), "subroutine" | "function") {
+            return None;
+        }
+
+        let max_count = settings.max_internal_procedures;
+        let count = node
+            .named_children(&mut node.walk())
+            .filter(|child| matches!(child.kind(), "subroutine" | "function"))
+            .count();
+        if count <= max_count {
+            return None;
+        }
+
+        let name_node = parent.child(0)?.child_with_name("name")?;
+        let name = name_node.to_text(src.source_text())?.to_string();
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name,
+                count,
+                max_count
+            },
+            &name_node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["internal_procedures"]
+    }
+}
@@ -0,0 +1,144 @@
+use crate::rules::obsolescent::include_statements::FIXED_FORM_EXTS;
+use crate::settings::Settings;
+use crate::TextRule;
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::{OneIndexed, SourceFile};
+use ruff_text_size::{TextLen, TextRange};
+use std::path::Path;
+
+/// ## What it does
+/// Checks that continuation lines inside brackets line up with the first
+/// character after the opening bracket.
+///
+/// ## Why is this bad?
+/// Lining up the arguments of a long procedure call or array constructor
+/// makes it clear which bracket they belong to:
+///
+/// ```f90
+/// call set_boundaries(left, right, &
+///                     top, bottom)
+/// ```
+///
+/// By default, this rule only reports continuation lines that are indented no
+/// further than the start of the statement, as these are the hardest to read.
+/// Use `--strict-continuation-alignment` to require exact alignment instead.
+///
+/// Brackets that end their line, as in `call foo( &`, are treated as a
+/// deliberate hanging indent and are not checked. Fixed-form files are
+/// skipped.
+#[violation]
+pub struct MisalignedContinuation {
+    expected: usize,
+}
+
+impl AlwaysFixableViolation for MisalignedContinuation {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { expected } = self;
+        let expected = expected + 1;
+        format!("continuation line not aligned with opening bracket (column {expected})")
+    }
+
+    fn fix_title(&self) -> String {
+        format!("Align with opening bracket")
+    }
+}
+
+/// An unclosed bracket, with its zero-based line and character column
+struct OpenBracket {
+    line: usize,
+    column: usize,
+    hanging: bool,
+}
+
+impl TextRule for MisalignedContinuation {
+    fn check(settings: &Settings, source_file: &SourceFile) -> Vec<Diagnostic> {
+        let is_fixed_form = Path::new(source_file.name())
+            .extension()
+            .is_some_and(|ext| FIXED_FORM_EXTS.iter().any(|&x| x == ext));
+        if is_fixed_form {
+            return vec![];
+        }
+
+        let source = source_file.to_source_code();
+        let mut violations = Vec::new();
+        let mut brackets: Vec<OpenBracket> = Vec::new();
+        let mut continued = false;
+        let mut statement_indent = 0;
+        for (idx, line) in source.text().lines().enumerate() {
+            let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+            let content = line.trim_start();
+            if content.is_empty() || content.starts_with('!') {
+                continue;
+            }
+
+            if !continued {
+                brackets.clear();
+                statement_indent = indent;
+            } else if !content.starts_with('&') {
+                if let Some(bracket) = brackets.last().filter(|bracket| !bracket.hanging) {
+                    let misaligned = if settings.strict_continuation_alignment {
+                        indent != bracket.column + 1
+                    } else {
+                        indent <= statement_indent
+                    };
+                    if misaligned {
+                        let line_start = source.line_start(OneIndexed::from_zero_indexed(idx));
+                        let whitespace = line.text_len() - content.text_len();
+                        let edit = Edit::range_replacement(
+                            " ".repeat(bracket.column + 1),
+                            TextRange::at(line_start, whitespace),
+                        );
+                        let range =
+                            TextRange::at(line_start + whitespace, content.trim_end().text_len());
+                        violations.push(
+                            Diagnostic::new(
+                                Self {
+                                    expected: bracket.column + 1,
+                                },
+                                range,
+                            )
+                            .with_fix(Fix::safe_edit(edit)),
+                        );
+                    }
+                }
+            }
+
+            // Find brackets in the code part of the line, skipping strings and comments
+            let mut quote = None;
+            let mut code_end = line.chars().count();
+            for (column, c) in line.chars().enumerate() {
+                match (quote, c) {
+                    (Some(q), _) if c == q => quote = None,
+                    (Some(_), _) => {}
+                    (None, '"' | '\'') => quote = Some(c),
+                    (None, '!') => {
+                        code_end = column;
+                        break;
+                    }
+                    (None, '(' | '[') => brackets.push(OpenBracket {
+                        line: idx,
+                        column,
+                        hanging: false,
+                    }),
+                    (None, ')' | ']') => {
+                        brackets.pop();
+                    }
+                    _ => {}
+                }
+            }
+
+            let code: String = line.chars().take(code_end).collect();
+            let code = code.trim_end();
+            continued = code.ends_with('&') && quote.is_none();
+
+            // Brackets with nothing after them on their own line start a hanging indent
+            let code_width = code.trim_end_matches('&').trim_end().chars().count();
+            for bracket in brackets.iter_mut().filter(|bracket| bracket.line == idx) {
+                bracket.hanging = bracket.column + 1 == code_width;
+            }
+        }
+        violations
+    }
+}
@@ -0,0 +1,92 @@
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+const EXECUTABLE_KINDS: &[&str] = &[
+    "assignment_statement",
+    "pointer_association_statement",
+    "subroutine_call",
+    "keyword_statement",
+    "if_statement",
+    "do_loop_statement",
+    "select_case_statement",
+    "where_statement",
+    "forall_statement",
+    "associate_statement",
+    "block_construct",
+    "print_statement",
+    "write_statement",
+    "read_statement",
+    "open_statement",
+    "close_statement",
+    "inquire_statement",
+    "file_position_statement",
+    "allocate_statement",
+    "deallocate_statement",
+    "nullify_statement",
+    "stop_statement",
+];
+
+/// ## What it does
+/// Checks for variable declarations that come after executable statements in
+/// the same scope.
+///
+/// ## Why is this bad?
+/// The standard requires all declarations to come before the first executable
+/// statement of a scoping unit. Some compilers accept them later on as an
+/// extension, but others will reject the code, and readers expect to find all
+/// of a procedure's variables listed together at the top. If a variable is
+/// only needed in one part of a procedure, a `block` construct can be used to
+/// declare it close to where it is used.
+///
+/// ## Example
+/// ```f90
+/// subroutine foo()
+///   real :: x
+///   x = 1.0
+///   real :: y
+///   y = 2.0 * x
+/// end subroutine foo
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// subroutine foo()
+///   real :: x, y
+///   x = 1.0
+///   y = 2.0 * x
+/// end subroutine foo
+/// ```
+#[violation]
+pub struct DeclarationAfterExecutable {}
+
+impl Violation for DeclarationAfterExecutable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Variable declaration after executable statements")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Move to the top of the scope".to_string())
+    }
+}
+
+impl AstRule for DeclarationAfterExecutable {
+    fn check(_settings: &Settings, node: &Node, _src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let mut sibling = node.prev_named_sibling();
+        while let Some(prev) = sibling {
+            if EXECUTABLE_KINDS.contains(&prev.kind()) {
+                return some_vec!(Diagnostic::from_node(Self {}, node));
+            }
+            sibling = prev.prev_named_sibling();
+        }
+        None
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
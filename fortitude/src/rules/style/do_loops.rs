@@ -0,0 +1,119 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// Simple statements that can go in a one-line `do` loop
+const SIMPLE_STATEMENTS: &[&str] = &[
+    "assignment_statement",
+    "pointer_association_statement",
+    "subroutine_call",
+    "print_statement",
+    "write_statement",
+    "read_statement",
+];
+
+/// ## What it does
+/// Checks for unlabelled `do` loops that contain a single, simple statement,
+/// and would fit on one line.
+///
+/// The maximum length of the one-line form, including indentation, can be set
+/// using `--max-do-single-line-length=N`, and defaults to 60 characters.
+///
+/// ## Why is this bad?
+/// Three lines for a loop around one short statement spreads out simple code.
+/// Often the loop can be replaced entirely by an array expression, which is
+/// shorter and makes the intent clearer. Otherwise, the loop can be written on
+/// one line, separating the statements with semicolons.
+///
+/// ## Example
+/// ```f90
+/// do i = 1, n
+///   x(i) = 0.0
+/// end do
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// x(1:n) = 0.0
+/// ```
+/// or:
+/// ```f90
+/// do i = 1, n; x(i) = 0.0; end do
+/// ```
+#[violation]
+pub struct SingleStatementDoLoop {
+    inline: String,
+}
+
+impl Violation for SingleStatementDoLoop {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'do' loop contains a single statement")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { inline } = self;
+        Some(format!("Use an array expression, or write as '{inline}'"))
+    }
+}
+
+impl AstRule for SingleStatementDoLoop {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let node_text = node.to_text(text)?;
+        let header = node_text.lines().next()?.trim_end();
+        // Skip labelled or named loops, and anything with continuations,
+        // comments, or statements already on the same line
+        if !regex_is_match!(r"^(?i)do\b", header)
+            || regex_is_match!(r"^(?i)do\s*\d", header)
+            || header.contains(['&', '!', ';'])
+        {
+            return None;
+        }
+
+        let end = node.child_with_name("end_do_loop_statement")?;
+        let body: Vec<Node> = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.start_byte() > node.start_byte() + header.len())
+            .filter(|child| child.id() != end.id())
+            .collect();
+        let [statement] = body.as_slice() else {
+            return None;
+        };
+        if !SIMPLE_STATEMENTS.contains(&statement.kind()) {
+            return None;
+        }
+        let statement_text = statement.to_text(text)?;
+        if statement_text.contains(['\n', '&']) {
+            return None;
+        }
+
+        let end_text = end.to_text(text)?;
+        if !regex_is_match!(r"^(?i)end\s*do$", end_text) {
+            return None;
+        }
+
+        let inline = format!("{header}; {statement_text}; {end_text}");
+        let indent = node.start_position().column;
+        if indent + inline.len() > settings.max_do_single_line_length {
+            return None;
+        }
+
+        let start = TextSize::try_from(node.start_byte()).unwrap();
+        let length = TextSize::try_from(header.len()).unwrap();
+        some_vec!(Diagnostic::new(
+            Self { inline },
+            TextRange::at(start, length)
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["do_loop_statement"]
+    }
+}
@@ -112,3 +112,88 @@ impl AstRule for UnnamedEndStatement {
         ]
     }
 }
+
+/// ## What does it do?
+/// Checks that named `end` statements use the same name as the construct
+/// they're ending
+///
+/// ## Why is this bad?
+/// The name on an `end` statement must match the name of the construct it
+/// ends, for example:
+///
+/// ```f90
+/// subroutine foo()
+///   ...
+/// end subroutine bar
+/// ```
+///
+/// is an error, usually caused by renaming a procedure or copying it from
+/// elsewhere. Some compilers only warn about this.
+#[violation]
+pub struct MismatchedEndStatementName {
+    statement: String,
+    name: String,
+    actual: String,
+}
+
+impl AlwaysFixableViolation for MismatchedEndStatementName {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            statement,
+            name,
+            actual,
+        } = self;
+        format!("end statement name '{actual}' does not match {statement} '{name}'")
+    }
+
+    fn fix_title(&self) -> String {
+        let Self { name, .. } = self;
+        format!("Rename to '{name}'")
+    }
+}
+
+impl AstRule for MismatchedEndStatementName {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let end_name = node.child_with_name("name")?;
+        let actual = end_name.to_text(text)?;
+
+        let declaration = node.parent()?;
+        let (statement, statement_kind) = map_declaration(declaration.kind());
+        let name_kind = match statement_kind {
+            "derived_type_statement" => "type_name",
+            _ => "name",
+        };
+        let name = declaration
+            .child_with_name(statement_kind)?
+            .child_with_name(name_kind)?
+            .to_text(text)?;
+        if name.eq_ignore_ascii_case(actual) {
+            return None;
+        }
+
+        let fix = Fix::safe_edit(end_name.edit_replacement(src, name.to_string()));
+        some_vec![Diagnostic::from_node(
+            Self {
+                statement: statement.to_string(),
+                name: name.to_string(),
+                actual: actual.to_string(),
+            },
+            &end_name
+        )
+        .with_fix(fix)]
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec![
+            "end_module_statement",
+            "end_submodule_statement",
+            "end_program_statement",
+            "end_function_statement",
+            "end_subroutine_statement",
+            "end_module_procedure_statement",
+            "end_type_statement",
+        ]
+    }
+}
@@ -0,0 +1,66 @@
+use crate::ast::FortitudeNode;
+use crate::rules::typing::implicit_typing::implicit_statement_is_none;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What does it do?
+/// Checks for `implicit` statements that set up implicit typing rules for
+/// ranges of letters, such as `implicit real (a-h, o-z)`.
+///
+/// ## Why is this bad?
+/// Implicit typing rules are a holdover from FORTRAN 77, and mean that any
+/// misspelt variable name silently creates a new variable. They also make
+/// the type of a variable depend on its first letter, which is easy to forget
+/// when reading the code. Use `implicit none` and declare every variable
+/// explicitly instead.
+///
+/// ## Example
+/// ```f90
+/// program test
+///   implicit real (a-h, o-z)
+///   x = 1.0
+/// end program test
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// program test
+///   implicit none
+///   real :: x
+///   x = 1.0
+/// end program test
+/// ```
+#[violation]
+pub struct ImplicitLetterRanges {
+    statement: String,
+}
+
+impl Violation for ImplicitLetterRanges {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { statement } = self;
+        format!("'{statement}' sets implicit typing rules")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use 'implicit none' and declare all variables explicitly".to_string())
+    }
+}
+
+impl AstRule for ImplicitLetterRanges {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if implicit_statement_is_none(node) {
+            return None;
+        }
+        let statement = node.to_text(src.source_text())?.trim().to_string();
+        some_vec!(Diagnostic::from_node(Self { statement }, node))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["implicit_statement"]
+    }
+}
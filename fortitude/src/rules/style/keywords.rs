@@ -0,0 +1,91 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks that each keyword is written with the same case throughout a file.
+///
+/// ## Why is this bad?
+/// Fortran is case-insensitive, so `subroutine`, `Subroutine` and `SUBROUTINE`
+/// are all equivalent. Mixing several styles in the same file is distracting
+/// and makes the code harder to search. Whichever style is chosen, it should be
+/// used consistently.
+///
+/// This rule takes the first use of each keyword in a file as the preferred
+/// style, and reports any later uses that differ from it.
+///
+/// ## Example
+/// ```f90
+/// subroutine foo()
+/// end subroutine foo
+///
+/// SUBROUTINE bar()
+/// END SUBROUTINE bar
+/// ```
+#[violation]
+pub struct InconsistentKeywordCase {
+    keyword: String,
+    first: String,
+}
+
+impl Violation for InconsistentKeywordCase {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { keyword, first } = self;
+        format!("keyword '{keyword}' is inconsistent with earlier use of '{first}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let Self { first, .. } = self;
+        Some(format!("Replace with '{first}'"))
+    }
+}
+
+impl AstRule for InconsistentKeywordCase {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let src = src.source_text();
+        let mut first_uses: HashMap<&str, &str> = HashMap::new();
+        let mut violations = Vec::new();
+
+        for token in node.descendants().filter(|token| !token.is_named()) {
+            let Some(text) = token.to_text(src) else {
+                continue;
+            };
+            // Keywords are anonymous nodes whose kind is the lowercase
+            // keyword itself, possibly with whitespace removed
+            let kind = token.kind();
+            if !kind.chars().all(|c| c.is_ascii_alphabetic())
+                || text.split_whitespace().collect::<String>().to_lowercase() != kind
+            {
+                continue;
+            }
+
+            // Only the case matters, not the spacing in keywords like `end do`
+            let first = *first_uses.entry(kind).or_insert(text);
+            if !text
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .eq(first.chars().filter(|c| !c.is_whitespace()))
+            {
+                violations.push(Diagnostic::from_node(
+                    Self {
+                        keyword: text.to_string(),
+                        first: first.to_string(),
+                    },
+                    &token,
+                ));
+            }
+        }
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["translation_unit"]
+    }
+}
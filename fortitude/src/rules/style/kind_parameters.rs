@@ -0,0 +1,127 @@
+use crate::ast::{dtype_is_plain_number, scoping_unit, FortitudeNode};
+use crate::rules::typing::literal_kinds::{integer_literal_kind, iso_fortran_env_param};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex;
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for kinds set with a number literal, such as `real(8)`, where the
+/// equivalent named constant from `iso_fortran_env` is already in scope.
+///
+/// ## Why is this bad?
+/// Once `iso_fortran_env` has been imported, mixing `real(8)` and
+/// `real(real64)` in the same code is inconsistent, and makes it harder to
+/// change the kinds later. The fix uses the local name of the constant, so
+/// renamed imports such as `dp => real64` are respected.
+///
+/// See also `literal-kind` (T011), which reports every kind set with a number
+/// literal.
+///
+/// ## Example
+/// ```f90
+/// use, intrinsic :: iso_fortran_env, only: real64
+/// real(8) :: x
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// use, intrinsic :: iso_fortran_env, only: real64
+/// real(real64) :: x
+/// ```
+#[violation]
+pub struct LiteralKindWithIsoFortranEnv {
+    literal: String,
+    parameter: String,
+}
+
+impl AlwaysFixableViolation for LiteralKindWithIsoFortranEnv {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { literal, parameter } = self;
+        format!("Kind set with number literal '{literal}' while '{parameter}' is in scope")
+    }
+
+    fn fix_title(&self) -> String {
+        let Self { parameter, .. } = self;
+        format!("Replace with '{parameter}'")
+    }
+}
+
+impl AstRule for LiteralKindWithIsoFortranEnv {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let dtype = node.child(0)?.to_text(text)?.to_lowercase();
+        if !dtype_is_plain_number(dtype.as_str()) {
+            return None;
+        }
+
+        let kind_node = node.child_by_field_name("kind")?;
+        let literal_node = integer_literal_kind(&kind_node, text)?;
+        let literal = literal_node.to_text(text)?;
+        let parameter = iso_fortran_env_param(&dtype, literal.parse().ok()?)?;
+        let local_name = iso_fortran_env_name(node, &parameter, text)?;
+
+        let fix = Fix::safe_edit(literal_node.edit_replacement(src, local_name.clone()));
+        some_vec!(Diagnostic::from_node(
+            Self {
+                literal: literal.to_string(),
+                parameter: local_name,
+            },
+            &literal_node
+        )
+        .with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["intrinsic_type"]
+    }
+}
+
+/// The local name of `parameter` from `iso_fortran_env`, if it has been
+/// imported into the scope of `node` or any enclosing scope
+pub(crate) fn iso_fortran_env_name(node: &Node, parameter: &str, src: &str) -> Option<String> {
+    let mut scope = scoping_unit(node);
+    while let Some(unit) = scope {
+        let name = unit
+            .named_children(&mut unit.walk())
+            .filter(|child| child.kind() == "use_statement")
+            .filter(|statement| {
+                statement
+                    .child_with_name("module_name")
+                    .and_then(|module| module.to_text(src))
+                    .is_some_and(|module| module.eq_ignore_ascii_case("iso_fortran_env"))
+            })
+            .find_map(|statement| imported_name(&statement, parameter, src));
+        if name.is_some() {
+            return name;
+        }
+        scope = scoping_unit(&unit);
+    }
+    None
+}
+
+/// The local name `use_statement` gives to `parameter`, either its own name or
+/// a rename such as `dp => real64`
+fn imported_name(use_statement: &Node, parameter: &str, src: &str) -> Option<String> {
+    let statement = use_statement.to_text(src)?;
+    let renamed = regex!(r"(\w+)\s*=>\s*(\w+)")
+        .captures_iter(statement)
+        .find(|captures| captures[2].eq_ignore_ascii_case(parameter));
+    if let Some(captures) = renamed {
+        return Some(captures[1].to_string());
+    }
+
+    let Some(items) = use_statement.child_with_name("included_items") else {
+        return Some(parameter.to_string());
+    };
+    items
+        .named_descendants()
+        .filter(|item| item.kind() == "identifier")
+        .filter_map(|item| item.to_text(src))
+        .find(|item| item.eq_ignore_ascii_case(parameter))
+        .map(|item| item.to_string())
+}
@@ -1,9 +1,24 @@
+pub mod contains;
+pub mod continuation;
+pub mod declaration_position;
+pub mod do_loops;
 pub mod double_colon_in_decl;
 pub mod end_statements;
 pub mod exit_labels;
+pub mod implicit_statements;
+pub mod keywords;
+pub mod kind_parameters;
 pub mod line_length;
+pub mod module_procedures;
 pub mod old_style_array_literal;
+pub mod opaque_types;
+pub mod parameter_declarations;
 pub mod relational_operators;
+pub mod select_case;
+pub mod todo_comments;
+pub mod use_only;
+pub mod use_position;
+pub mod use_renames;
 pub mod whitespace;
 
 #[cfg(test)]
@@ -16,7 +31,7 @@ mod tests {
     use test_case::test_case;
 
     use crate::registry::Rule;
-    use crate::settings::Settings;
+    use crate::settings::{MaxCommentSpaces, OperatorSpacing, Settings};
     use crate::test::test_path;
 
     #[test_case(Rule::LineTooLong, Path::new("S001.f90"))]
@@ -27,6 +42,26 @@ mod tests {
     #[test_case(Rule::MissingDoubleColon, Path::new("S071.f90"))]
     #[test_case(Rule::TrailingWhitespace, Path::new("S101.f90"))]
     #[test_case(Rule::IncorrectSpaceBeforeComment, Path::new("S102.f90"))]
+    #[test_case(Rule::InconsistentKeywordCase, Path::new("S481.f90"))]
+    #[test_case(Rule::IncorrectSpaceAroundOperator, Path::new("S491.f90"))]
+    #[test_case(Rule::MultipleBlankLines, Path::new("S501.f90"))]
+    #[test_case(Rule::EmptyContains, Path::new("S511.f90"))]
+    #[test_case(Rule::MisalignedContinuation, Path::new("S521.f90"))]
+    #[test_case(Rule::MisplacedUseStatement, Path::new("S541.f90"))]
+    #[test_case(Rule::MismatchedEndStatementName, Path::new("S551.f90"))]
+    #[test_case(Rule::InaccessibleDerivedType, Path::new("S561.f90"))]
+    #[test_case(Rule::MixedParameterDeclaration, Path::new("S571.f90"))]
+    #[test_case(Rule::ImplicitLetterRanges, Path::new("S581.f90"))]
+    #[test_case(Rule::InconsistentUseOnly, Path::new("S591.f90"))]
+    #[test_case(Rule::UnknownModuleProcedure, Path::new("S601.f90"))]
+    #[test_case(Rule::CommentSpacingOutOfRange, Path::new("S611.f90"))]
+    #[test_case(Rule::UnreachableCase, Path::new("S621.f90"))]
+    #[test_case(Rule::TooManyInternalProcedures, Path::new("S631.f90"))]
+    #[test_case(Rule::LiteralKindWithIsoFortranEnv, Path::new("S641.f90"))]
+    #[test_case(Rule::DeclarationAfterExecutable, Path::new("S651.f90"))]
+    #[test_case(Rule::TodoComment, Path::new("S661.f90"))]
+    #[test_case(Rule::SingleStatementDoLoop, Path::new("S671.f90"))]
+    #[test_case(Rule::DuplicateUseRename, Path::new("S681.f90"))]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -41,7 +76,6 @@ mod tests {
     #[test_case(Rule::LineTooLong, Path::new("S001_line_length_20.f90"))]
     fn line_too_long_line_length_20(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
-        #[allow(clippy::needless_update)]
         let settings = Settings {
             line_length: 20,
             ..Settings::default()
@@ -54,4 +88,99 @@ mod tests {
         assert_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test_case(Rule::IncorrectSpaceAroundOperator, Path::new("S491_never.f90"))]
+    fn incorrect_space_around_operator_never(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            operator_spacing: OperatorSpacing::Never,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::MisalignedContinuation, Path::new("S521_strict.f90"))]
+    fn misaligned_continuation_strict(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            strict_continuation_alignment: true,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::CommentSpacingOutOfRange, Path::new("S611_unlimited.f90"))]
+    fn comment_spacing_out_of_range_unlimited(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            min_comment_spaces: 1,
+            max_comment_spaces: MaxCommentSpaces::Unlimited,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::TooManyInternalProcedures, Path::new("S631.f90"))]
+    fn too_many_internal_procedures_max(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            max_internal_procedures: 7,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
+
+    #[test_case(Rule::TodoComment, Path::new("S661_custom_markers.f90"))]
+    fn todo_comment_custom_markers(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let settings = Settings {
+            todo_markers: vec!["OPTIMIZE".to_string(), "REVIEW".to_string()],
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(Rule::SingleStatementDoLoop, Path::new("S671.f90"))]
+    fn single_statement_do_loop_short_lines(rule_code: Rule, path: &Path) -> Result<()> {
+        let settings = Settings {
+            max_do_single_line_length: 30,
+            ..Settings::default()
+        };
+        let diagnostics = test_path(
+            Path::new("style").join(path).as_path(),
+            &[rule_code],
+            &settings,
+        )?;
+        assert!(diagnostics.is_empty());
+        Ok(())
+    }
 }
@@ -0,0 +1,136 @@
+use crate::ast::{scoping_unit, FortitudeNode};
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_is_match;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `module procedure` statements in interface blocks that name a
+/// procedure that doesn't exist.
+///
+/// ## Why is this bad?
+/// Every name in a `module procedure` statement must be a module procedure
+/// that is accessible in the current scope. A name that doesn't match is
+/// usually a typo, or a procedure that has been renamed or removed, and the
+/// module will fail to compile.
+///
+/// This rule only checks procedures in the same file. It doesn't fire if any
+/// enclosing scope has a `use` statement without an `only` clause, as the
+/// procedure may come from that module.
+///
+/// ## Example
+/// ```f90
+/// module shapes
+///   implicit none
+///   interface area
+///     module procedure circle_area, square_aera
+///   end interface area
+/// contains
+///   real function circle_area(r)
+///   ...
+///   real function square_area(s)
+///   ...
+/// end module shapes
+/// ```
+#[violation]
+pub struct UnknownModuleProcedure {
+    name: String,
+}
+
+impl Violation for UnknownModuleProcedure {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("'module procedure' refers to unknown procedure '{name}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Check the spelling, or define the procedure in this module".to_string())
+    }
+}
+
+impl AstRule for UnknownModuleProcedure {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let statements: Vec<Node> = node
+            .named_children(&mut node.walk())
+            .filter(|child| {
+                child.to_text(text).is_some_and(|statement| {
+                    regex_is_match!(r"^(?i)module\s+procedure\b", statement)
+                })
+            })
+            .collect();
+        if statements.is_empty() {
+            return None;
+        }
+
+        let mut known = Vec::new();
+        let mut scope = scoping_unit(node);
+        while let Some(unit) = scope {
+            for child in unit.named_children(&mut unit.walk()) {
+                match child.kind() {
+                    "use_statement" => {
+                        // Give up if a module without `only` could provide anything
+                        let items = child.child_with_name("included_items")?;
+                        known.extend(
+                            items
+                                .named_descendants()
+                                .filter(|item| item.kind() == "identifier")
+                                .filter_map(|item| item.to_text(text))
+                                .map(|item| item.to_lowercase()),
+                        );
+                    }
+                    "internal_procedures" => {
+                        known.extend(
+                            child
+                                .named_children(&mut child.walk())
+                                .filter_map(|procedure| {
+                                    procedure
+                                        .child_with_name(&format!(
+                                            "{}_statement",
+                                            procedure.kind()
+                                        ))?
+                                        .child_with_name("name")?
+                                        .to_text(text)
+                                })
+                                .map(|name| name.to_lowercase()),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            scope = scoping_unit(&unit);
+        }
+
+        let violations = statements
+            .iter()
+            .flat_map(|statement| {
+                statement
+                    .named_descendants()
+                    .filter(|child| child.kind() == "identifier")
+                    .collect::<Vec<_>>()
+            })
+            .filter_map(|procedure| {
+                let name = procedure.to_text(text)?;
+                if known.contains(&name.to_lowercase()) {
+                    return None;
+                }
+                Some(Diagnostic::from_node(
+                    Self {
+                        name: name.to_string(),
+                    },
+                    &procedure,
+                ))
+            })
+            .collect();
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["interface"]
+    }
+}
@@ -0,0 +1,139 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for derived types with private components that have no public
+/// components or type-bound procedures, and no constructor.
+///
+/// ## Why is this bad?
+/// A type whose components are all private can't be used for anything outside
+/// of its module unless it also provides some public way to work with it. This
+/// is usually a sign that a `public` attribute or type-bound procedure has
+/// been forgotten.
+///
+/// A generic interface with the same name as the type is treated as a
+/// constructor, and is enough for the type to be considered usable.
+///
+/// ## Example
+/// ```f90
+/// type :: counter_t
+///   private
+///   integer :: count = 0
+/// end type counter_t
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// type :: counter_t
+///   private
+///   integer :: count = 0
+/// contains
+///   procedure :: increment
+/// end type counter_t
+/// ```
+#[violation]
+pub struct InaccessibleDerivedType {
+    name: String,
+}
+
+impl Violation for InaccessibleDerivedType {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { name } = self;
+        format!("derived type '{name}' has no public components or type-bound procedures")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Make a component public, or add a type-bound procedure".to_string())
+    }
+}
+
+impl AstRule for InaccessibleDerivedType {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        node.child_with_name("private_statement")?;
+
+        let statement = node.child_with_name("derived_type_statement")?;
+        let name = statement.child_with_name("type_name")?.to_text(text)?;
+        // Private types aren't meant to be used outside the module anyway
+        if attributes(&statement, text).contains(&"private".to_string()) {
+            return None;
+        }
+
+        let has_public_component = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "variable_declaration")
+            .any(|declaration| {
+                declaration
+                    .children_by_field_name("attribute", &mut declaration.walk())
+                    .filter_map(|attribute| attribute.to_text(text))
+                    .any(|attribute| attribute.eq_ignore_ascii_case("public"))
+            });
+        if has_public_component {
+            return None;
+        }
+
+        if let Some(procedures) = node.child_with_name("derived_type_procedures") {
+            let private_by_default = procedures.child_with_name("private_statement").is_some();
+            let has_public_procedure = procedures
+                .named_children(&mut procedures.walk())
+                .filter(|child| child.kind() == "procedure_statement")
+                .any(|binding| {
+                    let attributes = attributes(&binding, text);
+                    if private_by_default {
+                        attributes.contains(&"public".to_string())
+                    } else {
+                        !attributes.contains(&"private".to_string())
+                    }
+                });
+            if has_public_procedure {
+                return None;
+            }
+        }
+
+        if let Some(module) = node.parent() {
+            let has_constructor = module
+                .named_descendants()
+                .filter(|child| child.kind() == "interface_statement")
+                .filter_map(|interface| interface.to_text(text))
+                .filter_map(|interface| regex_captures!(r"^(?i)interface\s+(\w+)", interface))
+                .any(|(_, generic)| generic.eq_ignore_ascii_case(name));
+            if has_constructor {
+                return None;
+            }
+        }
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                name: name.to_string()
+            },
+            &statement
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["derived_type_definition"]
+    }
+}
+
+/// Lower-case attributes of a `type` or `procedure` statement, from the text
+/// before the `::`
+fn attributes(statement: &Node, src: &str) -> Vec<String> {
+    let Some(text) = statement.to_text(src) else {
+        return vec![];
+    };
+    let Some((attributes, _)) = text.split_once("::") else {
+        return vec![];
+    };
+    attributes
+        .split(',')
+        .skip(1)
+        .map(|attribute| attribute.trim().to_lowercase())
+        .collect()
+}
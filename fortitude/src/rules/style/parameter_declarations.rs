@@ -0,0 +1,116 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use itertools::Itertools;
+use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `parameter` declarations that also list variables without an
+/// initial value.
+///
+/// ## Why is this bad?
+/// Attributes apply to every entity in a declaration, so in
+/// `real, parameter :: a = 1.0, b`, `b` is also a `parameter` and must be
+/// given a value. This is most likely a constant and a variable that have been
+/// mixed up in one statement. Declaring them separately makes it clear which
+/// names are constants.
+///
+/// The fix moves the names without a value into a new declaration without
+/// the `parameter` attribute. This is unsafe, as they may instead have been
+/// missing their values.
+///
+/// ## Example
+/// ```f90
+/// real, parameter :: a = 1.0, b
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// real, parameter :: a = 1.0
+/// real :: b
+/// ```
+#[violation]
+pub struct MixedParameterDeclaration {
+    names: String,
+}
+
+impl Violation for MixedParameterDeclaration {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { names } = self;
+        format!("'parameter' declaration also lists {names} without a value")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Split into separate 'parameter' and variable declarations".to_string())
+    }
+}
+
+impl AstRule for MixedParameterDeclaration {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let attributes: Vec<&str> = node
+            .children_by_field_name("attribute", &mut node.walk())
+            .filter_map(|attribute| attribute.to_text(text))
+            .collect();
+        if !attributes
+            .iter()
+            .any(|attribute| attribute.eq_ignore_ascii_case("parameter"))
+        {
+            return None;
+        }
+
+        let (initialised, uninitialised): (Vec<Node>, Vec<Node>) = node
+            .children_by_field_name("declarator", &mut node.walk())
+            .partition(|declarator| declarator.kind() == "init_declarator");
+        if uninitialised.is_empty() {
+            return None;
+        }
+
+        let declarator_list = |declarators: &[Node]| {
+            declarators
+                .iter()
+                .filter_map(|declarator| declarator.to_text(text))
+                .join(", ")
+        };
+        let names = uninitialised
+            .iter()
+            .filter_map(|declarator| declarator.to_text(text))
+            .map(|name| format!("'{name}'"))
+            .join(", ");
+        let diagnostic = Diagnostic::from_node(Self { names }, node);
+
+        // Nothing to split if none of the entities have values
+        if initialised.is_empty() {
+            return some_vec!(diagnostic);
+        }
+
+        let type_ = node.child_by_field_name("type")?.to_text(text)?;
+        let other_attributes = attributes
+            .iter()
+            .filter(|attribute| !attribute.eq_ignore_ascii_case("parameter"))
+            .map(|attribute| format!(", {attribute}"))
+            .join("");
+        let all_attributes = attributes
+            .iter()
+            .map(|attribute| format!(", {attribute}"))
+            .join("");
+        let indent = " ".repeat(node.start_position().column);
+        let replacement = format!(
+            "{type_}{all_attributes} :: {}\n{indent}{type_}{other_attributes} :: {}",
+            declarator_list(&initialised),
+            declarator_list(&uninitialised)
+        );
+        let fix = Fix::unsafe_edit(node.edit_replacement(src, replacement));
+        some_vec!(diagnostic.with_fix(fix))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["variable_declaration"]
+    }
+}
@@ -0,0 +1,130 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex_captures;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `case` values in a `select case` construct that are already
+/// covered by an earlier `case`.
+///
+/// ## Why is this bad?
+/// Only the first matching `case` of a `select case` is ever executed, so a
+/// value or range that is entirely covered by an earlier one can never be
+/// reached. Overlapping cases are not permitted by the standard, but not all
+/// compilers diagnose them, and they usually indicate a typo in one of the
+/// ranges.
+///
+/// Only integer literals and ranges of integer literals are checked.
+///
+/// ## Example
+/// ```f90
+/// select case (i)
+/// case (1:5)
+///   print *, "small"
+/// case (3)
+///   print *, "three"
+/// end select
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// select case (i)
+/// case (3)
+///   print *, "three"
+/// case (1:2, 4:5)
+///   print *, "small"
+/// end select
+/// ```
+#[violation]
+pub struct UnreachableCase {
+    value: String,
+    previous: String,
+}
+
+impl Violation for UnreachableCase {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { value, previous } = self;
+        format!("'case ({value})' is unreachable, already covered by '{previous}'")
+    }
+}
+
+impl AstRule for UnreachableCase {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let mut seen: Vec<(i64, i64, &str)> = Vec::new();
+        let mut diagnostics = Vec::new();
+
+        let values = node
+            .named_children(&mut node.walk())
+            .filter(|child| child.kind() == "case_statement")
+            .filter_map(|case| case.child_with_name("case_value_range_list"))
+            .flat_map(|list| list.named_children(&mut list.walk()).collect::<Vec<_>>());
+
+        for value in values {
+            let Some(value_text) = value.to_text(text) else {
+                continue;
+            };
+            let Some((lower, upper)) = case_range(value_text) else {
+                continue;
+            };
+            if lower > upper {
+                // Empty range, can never match anyway
+                continue;
+            }
+
+            if let Some((_, _, previous)) = seen
+                .iter()
+                .find(|(seen_lower, seen_upper, _)| *seen_lower <= lower && upper <= *seen_upper)
+            {
+                diagnostics.push(Diagnostic::from_node(
+                    Self {
+                        value: value_text.to_string(),
+                        previous: previous.to_string(),
+                    },
+                    &value,
+                ));
+            } else {
+                seen.push((lower, upper, value_text));
+            }
+        }
+
+        Some(diagnostics)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["select_case_statement"]
+    }
+}
+
+/// The inclusive bounds of a `case` value, if it is an integer literal or a
+/// range of integer literals. Open ranges extend to the limits of `i64`.
+fn case_range(value: &str) -> Option<(i64, i64)> {
+    match value.split_once(':') {
+        Some((lower, upper)) => {
+            let lower = match lower.trim() {
+                "" => i64::MIN,
+                lower => integer_literal(lower)?,
+            };
+            let upper = match upper.trim() {
+                "" => i64::MAX,
+                upper => integer_literal(upper)?,
+            };
+            Some((lower, upper))
+        }
+        None => {
+            let value = integer_literal(value)?;
+            Some((value, value))
+        }
+    }
+}
+
+fn integer_literal(literal: &str) -> Option<i64> {
+    let (_, sign, digits) = regex_captures!(r"^\s*([+-]?)\s*(\d+)(?:_\w+)?\s*$", literal)?;
+    let value = digits.parse::<i64>().ok()?;
+    Some(if sign == "-" { -value } else { value })
+}
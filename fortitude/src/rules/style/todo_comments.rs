@@ -0,0 +1,62 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for comments starting with a marker such as `TODO` or `FIXME`.
+///
+/// The markers can be changed with `--todo-markers`, and default to `TODO`,
+/// `FIXME`, `HACK`, `XXX`, `BUG`, and `NOTE`. Markers are matched
+/// case-insensitively, and may be followed by a colon.
+///
+/// ## Why is this bad?
+/// These comments mark unfinished work or known problems. They aren't errors
+/// in themselves, but listing them makes it easy to keep track of them, for
+/// example in code review or before a release.
+///
+/// ## Example
+/// ```f90
+/// ! TODO: handle negative values
+/// ```
+#[violation]
+pub struct TodoComment {
+    marker: String,
+    comment: String,
+}
+
+impl Violation for TodoComment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { marker, comment } = self;
+        format!("'{marker}' comment: {comment}")
+    }
+}
+
+impl AstRule for TodoComment {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let comment = node.to_text(src.source_text())?.trim_end();
+        let body = comment.trim_start_matches('!').trim_start();
+
+        let marker = settings.todo_markers.iter().find(|marker| {
+            body.get(..marker.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(marker))
+                && !body[marker.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+        })?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                marker: marker.to_string(),
+                comment: comment.to_string(),
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["comment"]
+    }
+}
@@ -0,0 +1,81 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `use` statements without an `only` clause, for modules that are
+/// used with an `only` clause elsewhere in the same file.
+///
+/// ## Why is this bad?
+/// Mixing the two styles for the same module makes it unclear which names
+/// are meant to come from it. Where one scope carefully lists what it needs,
+/// another scope importing everything is usually an oversight, and makes it
+/// harder to see what depends on the module.
+///
+/// This rule only compares `use` statements within a single file.
+///
+/// ## Example
+/// ```f90
+/// module solver
+///   use constants, only: pi
+/// contains
+///   subroutine step()
+///     use constants
+///   end subroutine step
+/// end module solver
+/// ```
+#[violation]
+pub struct InconsistentUseOnly {
+    module: String,
+    line: usize,
+}
+
+impl Violation for InconsistentUseOnly {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { module, line } = self;
+        format!("'use {module}' without 'only', but it is used with 'only' on line {line}")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Add an 'only' clause".to_string())
+    }
+}
+
+impl AstRule for InconsistentUseOnly {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        if node.child_with_name("included_items").is_some() {
+            return None;
+        }
+        let text = src.source_text();
+        let module = node.child_with_name("module_name")?.to_text(text)?;
+
+        let root = node.ancestors().last()?;
+        let only_use = root
+            .named_descendants()
+            .filter(|child| child.kind() == "use_statement")
+            .filter(|statement| statement.child_with_name("included_items").is_some())
+            .find(|statement| {
+                statement
+                    .child_with_name("module_name")
+                    .and_then(|name| name.to_text(text))
+                    .is_some_and(|name| name.eq_ignore_ascii_case(module))
+            })?;
+
+        some_vec!(Diagnostic::from_node(
+            Self {
+                module: module.to_string(),
+                line: only_use.start_position().row + 1,
+            },
+            node
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["use_statement"]
+    }
+}
@@ -0,0 +1,71 @@
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `use` statements that come after `implicit` statements or
+/// declarations in the same scope.
+///
+/// ## Why is this bad?
+/// The standard requires `use` statements to come first in a scoping unit,
+/// before `implicit none` and any declarations. Some compilers accept them
+/// later on, but others will reject the code, and readers expect to find a
+/// scope's dependencies at the top.
+///
+/// ## Example
+/// ```f90
+/// subroutine foo()
+///   implicit none (type, external)
+///   real :: x
+///   use bar, only: baz
+/// end subroutine foo
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// subroutine foo()
+///   use bar, only: baz
+///   implicit none (type, external)
+///   real :: x
+/// end subroutine foo
+/// ```
+#[violation]
+pub struct MisplacedUseStatement {}
+
+impl Violation for MisplacedUseStatement {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("'use' statement after declarations")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Move to the top of the scope".to_string())
+    }
+}
+
+impl AstRule for MisplacedUseStatement {
+    fn check(_settings: &Settings, node: &Node, _src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let mut sibling = node.prev_named_sibling();
+        while let Some(prev) = sibling {
+            if matches!(
+                prev.kind(),
+                "implicit_statement"
+                    | "variable_declaration"
+                    | "parameter_statement"
+                    | "derived_type_definition"
+                    | "interface"
+            ) {
+                return some_vec!(Diagnostic::from_node(Self {}, node));
+            }
+            sibling = prev.prev_named_sibling();
+        }
+        None
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["use_statement"]
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ast::FortitudeNode;
+use crate::settings::Settings;
+use crate::{AstRule, FromAstNode};
+use lazy_regex::regex;
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::SourceFile;
+use ruff_text_size::{TextRange, TextSize};
+use tree_sitter::Node;
+
+/// ## What it does
+/// Checks for `use` statements that rename the same module entity to more than
+/// one local name in the same scope.
+///
+/// ## Why is this bad?
+/// Two local names for the same thing is legal, but confusing: readers will
+/// assume they are different, and may miss that changing one changes the
+/// other. Pick one name and use it throughout the scope.
+///
+/// ## Example
+/// ```f90
+/// use mesh, only: nodes => points
+/// use mesh, only: vertices => points
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// use mesh, only: nodes => points
+/// ```
+#[violation]
+pub struct DuplicateUseRename {
+    symbol: String,
+    module: String,
+    first: String,
+}
+
+impl Violation for DuplicateUseRename {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            symbol,
+            module,
+            first,
+        } = self;
+        format!("'{symbol}' from '{module}' is already imported as '{first}'")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use a single local name".to_string())
+    }
+}
+
+impl AstRule for DuplicateUseRename {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let module = module_name(node, text)?;
+
+        // Renames of the same module from earlier `use` statements in this scope
+        let mut previous: Vec<(String, String)> = Vec::new();
+        let mut sibling = node.prev_named_sibling();
+        while let Some(prev) = sibling {
+            if prev.kind() == "use_statement"
+                && module_name(&prev, text).is_some_and(|name| name.eq_ignore_ascii_case(module))
+            {
+                previous.extend(
+                    renames(&prev, text)
+                        .into_iter()
+                        .map(|(local, symbol, _)| (local.to_string(), symbol.to_string())),
+                );
+            }
+            sibling = prev.prev_named_sibling();
+        }
+
+        let mut violations = Vec::new();
+        for (local, symbol, offset) in renames(node, text) {
+            let first = previous
+                .iter()
+                .find(|(other_local, other_symbol)| {
+                    other_symbol.eq_ignore_ascii_case(symbol)
+                        && !other_local.eq_ignore_ascii_case(local)
+                })
+                .map(|(other_local, _)| other_local.clone());
+            if let Some(first) = first {
+                let start = TextSize::try_from(node.start_byte() + offset).unwrap();
+                let length = TextSize::try_from(local.len()).unwrap();
+                violations.push(Diagnostic::new(
+                    Self {
+                        symbol: symbol.to_string(),
+                        module: module.to_string(),
+                        first,
+                    },
+                    TextRange::at(start, length),
+                ));
+            }
+            previous.push((local.to_string(), symbol.to_string()));
+        }
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["use_statement"]
+    }
+}
+
+fn module_name<'a>(use_statement: &Node, src: &'a str) -> Option<&'a str> {
+    use_statement.child_with_name("module_name")?.to_text(src)
+}
+
+/// The renames in a `use` statement, as tuples of the local name, the module
+/// entity, and the offset of the local name from the start of the statement
+fn renames<'a>(use_statement: &Node, src: &'a str) -> Vec<(&'a str, &'a str, usize)> {
+    let Some(statement) = use_statement.to_text(src) else {
+        return Vec::new();
+    };
+    regex!(r"(\w+)\s*=>\s*(\w+)")
+        .captures_iter(statement)
+        .filter_map(|captures| {
+            let local = captures.get(1)?;
+            let symbol = captures.get(2)?;
+            Some((local.as_str(), symbol.as_str(), local.start()))
+        })
+        .collect()
+}
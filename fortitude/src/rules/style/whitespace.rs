@@ -1,11 +1,11 @@
 /// Defines rules that enforce widely accepted whitespace rules.
-use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix, Violation};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_source_file::{OneIndexed, SourceFile};
 use ruff_text_size::{TextLen, TextRange, TextSize};
 use tree_sitter::Node;
 
-use crate::settings::Settings;
+use crate::settings::{MaxCommentSpaces, OperatorSpacing, Settings};
 use crate::{ast::FortitudeNode, AstRule, FromAstNode, TextRule};
 
 /// ## What does it do?
@@ -102,3 +102,264 @@ impl AstRule for IncorrectSpaceBeforeComment {
         vec!["comment"]
     }
 }
+
+/// ## What does it do?
+/// Checks for inline comments where the number of spaces before the `!` is
+/// outside the range set by `--min-comment-spaces` and `--max-comment-spaces`.
+///
+/// ## Why is this bad?
+/// Many style guides require a fixed gap between code and inline comments,
+/// so that comments stand out from the code consistently. By default, this
+/// rule requires exactly two spaces. Unlike `incorrect-space-before-comment`
+/// (S102), which only sets a minimum, this rule can also limit the number of
+/// spaces.
+/// Use `--max-comment-spaces=unlimited` to only check the minimum.
+///
+/// ## Example
+/// ```f90
+/// x = 1 ! one space
+/// y = 2      ! six spaces
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// x = 1  ! two spaces
+/// y = 2  ! two spaces
+/// ```
+#[violation]
+pub struct CommentSpacingOutOfRange {
+    spaces: usize,
+    expected: String,
+    target: usize,
+}
+
+impl AlwaysFixableViolation for CommentSpacingOutOfRange {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self {
+            spaces, expected, ..
+        } = self;
+        let s = if *spaces == 1 { "" } else { "s" };
+        format!("inline comment preceded by {spaces} space{s}, expected {expected}")
+    }
+
+    fn fix_title(&self) -> String {
+        let Self { target, .. } = self;
+        let s = if *target == 1 { "" } else { "s" };
+        format!("Use {target} space{s} before the comment")
+    }
+}
+
+impl AstRule for CommentSpacingOutOfRange {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let source = src.to_source_code();
+        let comment_start = TextSize::try_from(node.start_byte()).unwrap();
+        let line_start = source.line_start(source.line_index(comment_start));
+        let line = source.slice(TextRange::new(line_start, comment_start));
+        // Skip comments on their own line
+        let code = line.trim_end();
+        if code.is_empty() {
+            return None;
+        }
+
+        let spaces = line.len() - code.len();
+        let min = settings.min_comment_spaces;
+        let (target, expected) = match settings.max_comment_spaces {
+            MaxCommentSpaces::Limit(max) => {
+                let max = max.max(min);
+                let expected = if min == max {
+                    format!("{min}")
+                } else {
+                    format!("{min} to {max}")
+                };
+                (spaces.clamp(min, max), expected)
+            }
+            MaxCommentSpaces::Unlimited => (spaces.max(min), format!("at least {min}")),
+        };
+        if spaces == target {
+            return None;
+        }
+
+        let whitespace_start = line_start + TextSize::try_from(code.len()).unwrap();
+        let edit = Edit::range_replacement(
+            " ".repeat(target),
+            TextRange::new(whitespace_start, comment_start),
+        );
+        some_vec!(Diagnostic::from_node(
+            Self {
+                spaces,
+                expected,
+                target,
+            },
+            node
+        )
+        .with_fix(Fix::safe_edit(edit)))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["comment"]
+    }
+}
+
+/// ## What does it do?
+/// Checks for binary operators that aren't surrounded by whitespace, or
+/// optionally, operators that are.
+///
+/// ## Why is this bad?
+/// Inconsistent spacing around operators, such as `a +b`, makes expressions
+/// harder to read and can suggest a grouping that isn't there. By default,
+/// this rule requires a space on both sides of each binary operator. This can
+/// be reversed with `--operator-spacing=never`, in which case any whitespace
+/// around a binary operator is reported instead.
+///
+/// Operators split across a line continuation are not checked.
+///
+/// ## Example
+/// ```f90
+/// x = a +b*c
+/// ```
+///
+/// Use instead:
+/// ```f90
+/// x = a + b * c
+/// ```
+#[violation]
+pub struct IncorrectSpaceAroundOperator {
+    operator: String,
+    spacing: OperatorSpacing,
+}
+
+impl Violation for IncorrectSpaceAroundOperator {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { operator, spacing } = self;
+        match spacing {
+            OperatorSpacing::Always => format!("missing space around operator '{operator}'"),
+            OperatorSpacing::Never => format!("unexpected space around operator '{operator}'"),
+        }
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        match self.spacing {
+            OperatorSpacing::Always => Some("add a space on both sides".to_string()),
+            OperatorSpacing::Never => Some("remove surrounding whitespace".to_string()),
+        }
+    }
+}
+
+impl AstRule for IncorrectSpaceAroundOperator {
+    fn check(settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let text = src.source_text();
+        let left = node.child_by_field_name("left")?;
+        let operator = node.child_by_field_name("operator")?;
+        let right = node.child_by_field_name("right")?;
+
+        let before = &text[left.end_byte()..operator.start_byte()];
+        let after = &text[operator.end_byte()..right.start_byte()];
+        if before.contains('&') || after.contains('&') {
+            return None;
+        }
+
+        let spacing = settings.operator_spacing;
+        let correct = match spacing {
+            OperatorSpacing::Always => !before.is_empty() && !after.is_empty(),
+            OperatorSpacing::Never => before.is_empty() && after.is_empty(),
+        };
+        if correct {
+            return None;
+        }
+
+        let operator_text = operator.to_text(text)?.to_string();
+        some_vec!(Diagnostic::from_node(
+            Self {
+                operator: operator_text,
+                spacing,
+            },
+            &operator
+        ))
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["math_expression", "relational_expression"]
+    }
+}
+
+/// ## What does it do?
+/// Checks for two or more consecutive blank lines inside a procedure.
+///
+/// ## Why is this bad?
+/// A single blank line is useful for separating logical sections of a
+/// procedure, but further blank lines add clutter without making the code any
+/// clearer, and reduce how much of the procedure fits on screen.
+#[violation]
+pub struct MultipleBlankLines {
+    count: usize,
+}
+
+impl AlwaysFixableViolation for MultipleBlankLines {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let Self { count } = self;
+        format!("too many blank lines ({count})")
+    }
+
+    fn fix_title(&self) -> String {
+        format!("Remove extra blank lines")
+    }
+}
+
+impl AstRule for MultipleBlankLines {
+    fn check(_settings: &Settings, node: &Node, src: &SourceFile) -> Option<Vec<Diagnostic>> {
+        let source = src.to_source_code();
+        let line_of = |byte: usize| source.line_index(TextSize::try_from(byte).unwrap());
+
+        // Blank lines in nested procedures are checked separately
+        let nested: Vec<_> = node
+            .named_descendants()
+            .filter(|child| matches!(child.kind(), "subroutine" | "function"))
+            .map(|child| (line_of(child.start_byte()), line_of(child.end_byte())))
+            .collect();
+
+        let first_line = line_of(node.start_byte());
+        let last_line = line_of(node.end_byte());
+        let mut violations = Vec::new();
+        let mut blank_lines = 0;
+        for idx in first_line.to_zero_indexed()..=last_line.to_zero_indexed() {
+            let line_index = OneIndexed::from_zero_indexed(idx);
+            let line = source.line_text(line_index);
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+
+            let in_nested = nested
+                .iter()
+                .any(|(start, end)| *start < line_index && line_index <= *end);
+            if blank_lines >= 2 && !in_nested {
+                // Keep the first blank line, delete the rest
+                let extra_start = OneIndexed::from_zero_indexed(idx - blank_lines + 1);
+                let edit = Edit::range_deletion(TextRange::new(
+                    source.line_start(extra_start),
+                    source.line_start(line_index),
+                ));
+
+                // Report on the contents of the line following the blank lines
+                let line_start = source.line_start(line_index);
+                let indent = line.text_len() - line.trim_start().text_len();
+                let range =
+                    TextRange::new(line_start + indent, line_start + line.trim_end().text_len());
+                violations.push(
+                    Diagnostic::new(Self { count: blank_lines }, range)
+                        .with_fix(Fix::safe_edit(edit)),
+                );
+            }
+            blank_lines = 0;
+        }
+
+        Some(violations)
+    }
+
+    fn entrypoints() -> Vec<&'static str> {
+        vec!["subroutine", "function"]
+    }
+}
@@ -8,7 +8,7 @@ use ruff_source_file::SourceFile;
 use ruff_text_size::TextSize;
 use tree_sitter::Node;
 
-fn implicit_statement_is_none(node: &Node) -> bool {
+pub(crate) fn implicit_statement_is_none(node: &Node) -> bool {
     if let Some(child) = node.child(1) {
         return child.kind() == "none";
     }
@@ -7,7 +7,7 @@ use ruff_macros::{derive_message_formats, violation};
 use ruff_source_file::SourceFile;
 use tree_sitter::Node;
 
-fn iso_fortran_env_param<S: AsRef<str>>(dtype: S, literal: u8) -> Option<String> {
+pub(crate) fn iso_fortran_env_param<S: AsRef<str>>(dtype: S, literal: u8) -> Option<String> {
     match dtype.as_ref() {
         "integer" | "logical" => {
             if matches!(literal, 1u8 | 2u8 | 4u8 | 8u8) {
@@ -127,7 +127,7 @@ impl AstRule for LiteralKind {
 }
 
 /// Return any kind spec that is a number literal
-fn integer_literal_kind<'a>(node: &'a Node, src: &str) -> Option<Node<'a>> {
+pub(crate) fn integer_literal_kind<'a>(node: &'a Node, src: &str) -> Option<Node<'a>> {
     if let Some(literal) = node.child_with_name("number_literal") {
         return Some(literal);
     }
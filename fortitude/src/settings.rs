@@ -7,7 +7,7 @@ use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use globset::{Glob, GlobSet, GlobSetBuilder};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
 use ruff_diagnostics::Applicability;
 use ruff_macros::CacheKey;
 use serde::{de, Deserialize, Deserializer, Serialize};
@@ -16,12 +16,183 @@ use crate::fs;
 use crate::rule_selector::RuleSelector;
 
 pub struct Settings {
+    pub ignore_allow_comments: bool,
     pub line_length: usize,
+    pub operator_spacing: OperatorSpacing,
+    pub require_real_leading_zero: bool,
+    pub allowed_volatile_names: NamePatternSet,
+    pub target_std: FortranStandard,
+    pub strict_continuation_alignment: bool,
+    pub coarray_support: bool,
+    pub min_comment_spaces: usize,
+    pub max_comment_spaces: MaxCommentSpaces,
+    pub prefer_mpi_f08: bool,
+    pub max_internal_procedures: usize,
+    pub require_explicit_output_unit: bool,
+    pub todo_markers: Vec<String>,
+    pub max_do_single_line_length: usize,
+    pub allowed_logging_modules: NamePatternSet,
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Self { line_length: 100 }
+        Self {
+            ignore_allow_comments: false,
+            line_length: 100,
+            operator_spacing: OperatorSpacing::default(),
+            require_real_leading_zero: false,
+            allowed_volatile_names: NamePatternSet::default(),
+            target_std: FortranStandard::default(),
+            strict_continuation_alignment: false,
+            coarray_support: false,
+            min_comment_spaces: 2,
+            max_comment_spaces: MaxCommentSpaces::default(),
+            prefer_mpi_f08: false,
+            max_internal_procedures: 5,
+            require_explicit_output_unit: false,
+            todo_markers: DEFAULT_TODO_MARKERS
+                .iter()
+                .map(|marker| marker.to_string())
+                .collect(),
+            max_do_single_line_length: 60,
+            allowed_logging_modules: NamePatternSet::default(),
+        }
+    }
+}
+
+/// Comment markers reported by `todo-comment`
+pub const DEFAULT_TODO_MARKERS: &[&str] = &["TODO", "FIXME", "HACK", "XXX", "BUG", "NOTE"];
+
+/// Whitespace style around binary operators
+#[derive(
+    Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, Hash, Default, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum OperatorSpacing {
+    #[default]
+    Always,
+    Never,
+}
+
+impl Display for OperatorSpacing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Always => "always",
+                Self::Never => "never",
+            }
+        )
+    }
+}
+
+/// Upper limit on the number of spaces before an inline comment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaxCommentSpaces {
+    Limit(usize),
+    Unlimited,
+}
+
+impl MaxCommentSpaces {
+    const EXPECTED_PATTERN: &'static str = "a number or \"unlimited\"";
+}
+
+impl Default for MaxCommentSpaces {
+    fn default() -> Self {
+        Self::Limit(2)
+    }
+}
+
+impl Display for MaxCommentSpaces {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Limit(limit) => write!(f, "{limit}"),
+            Self::Unlimited => write!(f, "unlimited"),
+        }
+    }
+}
+
+impl FromStr for MaxCommentSpaces {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("unlimited") {
+            return Ok(Self::Unlimited);
+        }
+        match s.trim().parse::<usize>() {
+            Ok(limit) => Ok(Self::Limit(limit)),
+            Err(_) => anyhow::bail!("Expected {}", Self::EXPECTED_PATTERN),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxCommentSpaces {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Number(usize),
+            Text(String),
+        }
+
+        match Value::deserialize(deserializer)? {
+            Value::Number(limit) => Ok(Self::Limit(limit)),
+            Value::Text(text) => Self::from_str(text.as_str()).map_err(|_| {
+                de::Error::invalid_value(
+                    de::Unexpected::Str(text.as_str()),
+                    &Self::EXPECTED_PATTERN,
+                )
+            }),
+        }
+    }
+}
+
+/// Revisions of the Fortran standard, in chronological order
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    Debug,
+    Hash,
+    Default,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum FortranStandard {
+    F77,
+    F90,
+    F95,
+    F2003,
+    F2008,
+    F2018,
+    #[default]
+    F2023,
+}
+
+impl Display for FortranStandard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::F77 => "Fortran 77",
+                Self::F90 => "Fortran 90",
+                Self::F95 => "Fortran 95",
+                Self::F2003 => "Fortran 2003",
+                Self::F2008 => "Fortran 2008",
+                Self::F2018 => "Fortran 2018",
+                Self::F2023 => "Fortran 2023",
+            }
+        )
     }
 }
 
@@ -225,6 +396,35 @@ impl FilePatternSet {
     }
 }
 
+/// A set of glob patterns matched case-insensitively against Fortran names
+#[derive(Debug, Clone, Default)]
+pub struct NamePatternSet {
+    pub set: GlobSet,
+}
+
+impl NamePatternSet {
+    pub fn try_from_iter<I, S>(patterns: I) -> Result<Self, anyhow::Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            builder.add(
+                GlobBuilder::new(pattern.as_ref())
+                    .case_insensitive(true)
+                    .build()?,
+            );
+        }
+        let set = builder.build()?;
+        Ok(NamePatternSet { set })
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.set.is_match(name)
+    }
+}
+
 /// Toggle for excluding files even when passed directly on the command line
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, CacheKey, is_macro::Is)]
 pub enum ExcludeMode {
@@ -335,6 +535,28 @@ impl Display for OutputFormat {
     }
 }
 
+/// Which files to list when only printing a summary of violations per file
+#[derive(
+    Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, Hash, Default, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SummaryMode {
+    /// Only files with violations
+    #[default]
+    Violations,
+    /// All checked files, including those without violations
+    All,
+}
+
+impl Display for SummaryMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Violations => write!(f, "violations"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Hash, is_macro::Is)]
 pub enum FixMode {
     Generate,
@@ -71,7 +71,7 @@ unknown-key = 1
       |
     2 | unknown-key = 1
       | ^^^^^^^^^^^
-    unknown field `unknown-key`, expected one of `files`, `fix`, `no-fix`, `unsafe-fixes`, `no-unsafe-fixes`, `show-fixes`, `no-show-fixes`, `fix-only`, `no-fix-only`, `output-format`, `preview`, `no-preview`, `progress-bar`, `ignore`, `select`, `extend-select`, `per-file-ignores`, `extend-per-file-ignores`, `file-extensions`, `exclude`, `extend-exclude`, `force-exclude`, `no-force-exclude`, `respect-gitignore`, `no-respect-gitignore`, `line-length`
+    unknown field `unknown-key`, expected one of `files`, `fix`, `no-fix`, `unsafe-fixes`, `no-unsafe-fixes`, `show-fixes`, `no-show-fixes`, `fix-only`, `no-fix-only`, `output-format`, `output-file`, `summary`, `preview`, `no-preview`, `target-std`, `progress-bar`, `ignore`, `select`, `extend-select`, `per-file-ignores`, `extend-per-file-ignores`, `ignore-allow-comments`, `no-ignore-allow-comments`, `file-extensions`, `exclude`, `extend-exclude`, `force-exclude`, `no-force-exclude`, `respect-gitignore`, `no-respect-gitignore`, `line-length`, `operator-spacing`, `require-real-leading-zero`, `no-require-real-leading-zero`, `allowed-volatile-names`, `strict-continuation-alignment`, `no-strict-continuation-alignment`, `coarray-support`, `no-coarray-support`, `min-comment-spaces`, `max-comment-spaces`, `prefer-mpi-f08`, `no-prefer-mpi-f08`, `max-internal-procedures`, `require-explicit-output-unit`, `no-require-explicit-output-unit`, `todo-markers`, `max-do-single-line-length`, `allowed-logging-modules`
     ");
     Ok(())
 }
@@ -1198,6 +1198,99 @@ end program test
     Ok(())
 }
 
+#[test]
+fn check_misspelled_per_line_ignores() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+! allow(impliict-typing)
+program test
+end program test
+"#,
+    )?;
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg(test_file)
+                         .arg("--select=E"),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] E011 Unknown rule selector: `impliict-typing`. Did you mean `implicit-typing`?
+      |
+    2 | ! allow(impliict-typing)
+      |         ^^^^^^^^^^^^^^^ E011
+    3 | program test
+    4 | end program test
+      |
+
+    fortitude: 1 files scanned.
+    Number of errors: 1
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn check_noqa_comments() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.  ! noqa: T021
+  logical*4, parameter :: false = .false.  ! NOQA
+  logical*4, parameter :: maybe = .false.
+end program test
+"#,
+    )?;
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg(test_file)
+                         .args(["--select=T021"]),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] T021 'logical*4' uses non-standard syntax
+      |
+    3 |   logical*4, parameter :: true = .true.  ! noqa: T021
+    4 |   logical*4, parameter :: false = .false.  ! NOQA
+    5 |   logical*4, parameter :: maybe = .false.
+      |          ^^ T021
+    6 | end program test
+      |
+      = help: Replace with 'logical(4)'
+
+    fortitude: 1 files scanned.
+    Number of errors: 1
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    No fixes available (1 hidden fix can be enabled with the `--unsafe-fixes` option).
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn apply_fixes_with_allow_comment() -> anyhow::Result<()> {
     let tempdir = TempDir::new()?;
@@ -1254,6 +1347,66 @@ end program foo
     Ok(())
 }
 
+#[test]
+fn apply_fixes_ignoring_allow_comments() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+! allow(superfluous-implicit-none)
+program foo
+  implicit none
+  real i
+  i = 4.0
+contains
+  subroutine bar
+    implicit none
+  end subroutine bar
+end program foo
+"#,
+    )?;
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=superfluous-implicit-none")
+                         .arg("--ignore-allow-comments")
+                         .arg("--fix")
+                         .arg(&test_file),
+                         @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    Number of errors: 1 (1 fixed, 0 remaining)
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+
+    let expected = r#"
+! allow(superfluous-implicit-none)
+program foo
+  implicit none
+  real i
+  i = 4.0
+contains
+  subroutine bar
+  end subroutine bar
+end program foo
+"#
+    .to_string();
+
+    let transformed = fs::read_to_string(&test_file)?;
+    assert_eq!(transformed, expected);
+
+    Ok(())
+}
+
 #[test]
 fn check_toml_settings() -> anyhow::Result<()> {
     let tempdir = TempDir::new()?;
@@ -1487,3 +1640,273 @@ fn check_no_respect_gitignore() -> anyhow::Result<()> {
     ");
     Ok(())
 }
+
+#[test]
+fn check_stdin() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    assert_cmd::Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg("--select=T001")
+        .arg("-")
+        .current_dir(tempdir.path())
+        .write_stdin("program test\nend program test\n")
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains(
+            "-:1:1: T001 program missing 'implicit none'",
+        ));
+    Ok(())
+}
+
+#[test]
+fn check_stdin_filename_per_file_ignores() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let source = "program test\nend program test\n";
+
+    // Diagnostics are reported using the given filename
+    assert_cmd::Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg("--select=T001")
+        .arg("--stdin-filename=test_foo.f90")
+        .arg("--per-file-ignores=other_*.f90:T001")
+        .current_dir(tempdir.path())
+        .write_stdin(source)
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains(
+            "test_foo.f90:1:1: T001 program missing 'implicit none'",
+        ));
+
+    // and it is matched against per-file-ignores
+    assert_cmd::Command::cargo_bin(BIN_NAME)?
+        .arg("check")
+        .arg("--select=T001")
+        .arg("--stdin-filename=test_foo.f90")
+        .arg("--per-file-ignores=test_*.f90:T001")
+        .current_dir(tempdir.path())
+        .write_stdin(source)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn check_concise_output() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+end program
+"#,
+    )?;
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=T001,S061")
+                         .arg("--output-format=concise")
+                         .arg(test_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] T001 program missing 'implicit none'
+    [TEMP_FILE] S061 [*] end statement should be named.
+    fortitude: 1 files scanned.
+    Number of errors: 2
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    [*] 1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    ");
+    Ok(())
+}
+
+#[test]
+fn check_output_file() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+end program
+"#,
+    )?;
+    let output_file = tempdir.path().join("report.txt");
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=T001,S061")
+                         .arg("--output-format=concise")
+                         .arg(format!("--output-file={}", output_file.display()))
+                         .arg(&test_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    Number of errors: 2
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    [*] 1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    ");
+
+    let expected = format!(
+        "{0}:2:1: T001 program missing 'implicit none'\n{0}:4:1: S061 [*] end statement should be named.\n",
+        test_file.display()
+    );
+    assert_eq!(fs::read_to_string(&output_file)?, expected);
+    Ok(())
+}
+
+#[test]
+fn check_prefer_mpi_f08_preview() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let test_file = tempdir.path().join("test.f90");
+    fs::write(
+        &test_file,
+        r#"
+program test
+  use mpi
+  implicit none (type, external)
+end program test
+"#,
+    )?;
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=OB181")
+                         .arg("--prefer-mpi-f08")
+                         .arg(&test_file),
+                         @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fortitude: 1 files scanned.
+    All checks passed!
+
+
+    ----- stderr -----
+    ");
+
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=OB181")
+                         .arg("--prefer-mpi-f08")
+                         .arg("--preview")
+                         .arg(&test_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] OB181 'use mpi' instead of Fortran 2008 'mpi_f08' module
+      |
+    2 | program test
+    3 |   use mpi
+      |       ^^^ OB181
+    4 |   implicit none (type, external)
+    5 | end program test
+      |
+      = help: Use 'mpi_f08'
+
+    fortitude: 1 files scanned.
+    Number of errors: 1
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+
+    ----- stderr -----
+    ");
+    Ok(())
+}
+
+#[test]
+fn check_summary() -> anyhow::Result<()> {
+    let tempdir = TempDir::new()?;
+    let bad_file = tempdir.path().join("bad.f90");
+    fs::write(
+        &bad_file,
+        r#"
+program test
+  logical*4, parameter :: true = .true.
+end program
+"#,
+    )?;
+    let good_file = tempdir.path().join("good.f90");
+    fs::write(
+        &good_file,
+        r#"
+program test
+  implicit none
+end program test
+"#,
+    )?;
+
+    apply_common_filters!();
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=T001,S061")
+                         .arg("--summary")
+                         .arg(&bad_file)
+                         .arg(&good_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] 2
+    fortitude: 2 files scanned.
+    Number of errors: 2
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    [*] 1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    ");
+
+    assert_cmd_snapshot!(Command::cargo_bin(BIN_NAME)?
+                         .arg("check")
+                         .arg("--select=T001,S061")
+                         .arg("--summary=all")
+                         .arg(&bad_file)
+                         .arg(&good_file),
+                         @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [TEMP_FILE] 2
+    [TEMP_FILE] 0
+    fortitude: 2 files scanned.
+    Number of errors: 2
+
+    For more information about specific rules, run:
+
+        fortitude explain X001,Y002,...
+
+    [*] 1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    ");
+    Ok(())
+}